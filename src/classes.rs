@@ -4,4 +4,13 @@ pub mod stats;
 pub mod clause;
 pub mod solver;
 pub mod formula;
-pub mod decision;
\ No newline at end of file
+pub mod decision;
+pub mod preprocessor;
+pub mod limits;
+pub mod progress;
+pub mod heuristic;
+pub mod reduction;
+pub mod local_search;
+pub mod cuber;
+pub mod xor;
+pub mod gauss;
\ No newline at end of file
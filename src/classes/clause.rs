@@ -13,6 +13,9 @@ pub struct Clause {
 
     two_watched_literals: (usize, usize),
 
+    lbd: usize,
+    activity: f64,
+
     pub learned_clause_is_used_somewhere: bool
 }
 
@@ -26,6 +29,9 @@ impl Clause {
 
             two_watched_literals: (0, 0),
 
+            lbd: 0,
+            activity: 0.0,
+
             learned_clause_is_used_somewhere: false
         }
     }
@@ -50,6 +56,46 @@ impl Clause {
         self.clause_id
     }
 
+    /// Get the literal block distance of a learned clause
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of decision levels of the literals when the clause was learned
+    /// 
+    pub fn get_lbd(&self) -> usize {
+        self.lbd
+    }
+
+    /// Set the literal block distance of a learned clause
+    /// 
+    /// # Arguments
+    /// 
+    /// * `lbd` - The number of decision levels of the literals
+    /// 
+    pub fn set_lbd(&mut self, lbd: usize) {
+        self.lbd = lbd;
+    }
+
+    /// Get the activity of a learned clause
+    /// 
+    /// # Returns
+    /// 
+    /// * `f64` - The activity, increased every time the clause is used in a conflict analysis
+    /// 
+    pub fn get_activity(&self) -> f64 {
+        self.activity
+    }
+
+    /// Set the activity of a learned clause
+    /// 
+    /// # Arguments
+    /// 
+    /// * `activity` - The activity
+    /// 
+    pub fn set_activity(&mut self, activity: f64) {
+        self.activity = activity;
+    }
+
     /// Load a string of literals into the clause
     /// 
     /// # Arguments
@@ -0,0 +1,273 @@
+use std::collections::HashSet;
+
+use crate::consts::{cube, sat::SAT};
+use crate::classes::{formula::Formula, limits::Limits, model::Model, stats::Stats};
+
+pub struct Cuber {
+    depth: usize,
+}
+
+/// The clauses of the formula with their occurrence lists, shared by the lookaheads of a split.
+struct Lookahead {
+    clauses: Vec<Vec<isize>>,
+    occurrences: Vec<Vec<usize>>,
+}
+
+impl Cuber {
+    pub fn new() -> Cuber {
+        Cuber {
+            depth: cube::DEFAULT_DEPTH,
+        }
+    }
+
+    /// Returns the maximum number of decisions of a cube.
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The depth of the split.
+    /// 
+    pub fn get_depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Set the maximum number of decisions of a cube, a formula is split in at most 2^depth cubes.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `depth` - The depth of the split.
+    /// 
+    pub fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+    }
+
+    /// Split the formula in cubes with a lookahead.
+    /// Every free variable is propagated with both values, the variable that reduces the most clauses in both branches
+    /// is decided. A literal whose propagation fails is a failed literal, its negation is added to the cube. A branch is
+    /// refuted if both values of a variable fail, and it is not a cube.
+    /// The disjunction of the cubes covers every model of the formula.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `formula` - The formula to split.
+    /// * `limits` - The limits of the solver.
+    /// * `stats` - The statistics of the solver.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<Vec<Vec<isize>>>` - The cubes, empty if every branch is refuted, None if a limit is reached.
+    /// 
+    pub fn cube(&self, formula: &Formula, limits: &Limits, stats: &mut Stats) -> Option<Vec<Vec<isize>>> {
+        let num_variables = formula.get_num_variables();
+        let clauses: Vec<Vec<isize>> = formula.get_clauses().iter()
+            .map(|clause| clause.iter_literals().cloned().collect())
+            .collect();
+        let mut occurrences: Vec<Vec<usize>> = vec![Vec::new(); 2 * num_variables + 2];
+        for (idx, clause) in clauses.iter().enumerate() {
+            for &literal in clause.iter() {
+                occurrences[Self::literal_index(literal)].push(idx);
+            }
+        }
+        let lookahead = Lookahead { clauses, occurrences };
+
+        // The unit clauses of the formula are propagated before the first split.
+        let mut assignment = Model::new(Some(num_variables));
+        let units: Vec<isize> = lookahead.clauses.iter().filter(|clause| clause.len() == 1).map(|clause| clause[0]).collect();
+        for unit in units {
+            match lookahead.propagate(&assignment, unit) {
+                Some((propagated, _)) => assignment = propagated,
+                None => return Some(Vec::new()),
+            }
+        }
+
+        let mut cubes: Vec<Vec<isize>> = Vec::new();
+        match self.split(&lookahead, assignment, Vec::new(), 0, &mut cubes, limits, stats) {
+            true => Some(cubes),
+            false => None,
+        }
+    }
+
+    /// A node of the split, it adds the failed literals to the cube and then it decides the best variable.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `lookahead` - The clauses of the formula.
+    /// * `assignment` - The literals of the cube with their propagation.
+    /// * `cube` - The literals of the cube.
+    /// * `depth` - The number of decisions of the cube.
+    /// * `cubes` - The cubes found so far.
+    /// * `limits` - The limits of the solver.
+    /// * `stats` - The statistics of the solver.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - False if a limit is reached.
+    /// 
+    fn split(&self, lookahead: &Lookahead, assignment: Model, cube: Vec<isize>, depth: usize, cubes: &mut Vec<Vec<isize>>, limits: &Limits, stats: &mut Stats) -> bool {
+        if limits.check(stats).is_some() {
+            return false;
+        }
+
+        let mut assignment = assignment;
+        let mut cube = cube;
+        if depth == self.depth {
+            cubes.push(cube);
+            stats.increase_cubes(1);
+            return true;
+        }
+
+        let (variable, positive, negative) = loop {
+            let mut best: Option<(usize, Model, Model)> = None;
+            let mut best_score = 0;
+            let mut failed: Option<isize> = None;
+
+            for variable in lookahead.free_variables(&assignment) {
+                let variable = variable as isize;
+                let positive = lookahead.propagate(&assignment, variable);
+                let negative = lookahead.propagate(&assignment, -variable);
+                match (positive, negative) {
+                    (None, None) => return true,
+                    (None, Some(_)) => failed = Some(-variable),
+                    (Some(_), None) => failed = Some(variable),
+                    (Some((positive, positive_reduced)), Some((negative, negative_reduced))) => {
+                        // The product favours the variables that reduce the formula in both branches.
+                        let score = (positive_reduced + 1) * (negative_reduced + 1);
+                        if best.is_none() || score > best_score {
+                            best_score = score;
+                            best = Some((variable as usize, positive, negative));
+                        }
+                        continue;
+                    },
+                }
+                break;
+            }
+
+            match failed {
+                Some(literal) => {
+                    // The negation of a failed literal is implied by the cube.
+                    assignment = lookahead.propagate(&assignment, literal).unwrap().0;
+                    cube.push(literal);
+                },
+                None => match best {
+                    Some(best) => break best,
+                    None => {
+                        cubes.push(cube);
+                        stats.increase_cubes(1);
+                        return true;
+                    },
+                },
+            }
+        };
+
+        for (literal, branch) in [(variable as isize, positive), (-(variable as isize), negative)] {
+            let mut branch_cube = cube.clone();
+            branch_cube.push(literal);
+            if !self.split(lookahead, branch, branch_cube, depth + 1, cubes, limits, stats) {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    /// Returns the index of a literal in the occurrence lists.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literal` - The literal.
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The index, the positive and the negative literal of a variable are adjacent.
+    /// 
+    fn literal_index(literal: isize) -> usize {
+        2 * literal.unsigned_abs() + if literal > 0 { 0 } else { 1 }
+    }
+}
+
+impl Lookahead {
+    /// Assign a literal and propagate it.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `assignment` - The current assignment, it is not changed.
+    /// * `literal` - The literal to assign.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<(Model, usize)>` - The new assignment and the number of unsatisfied clauses reduced by the propagation,
+    /// None if the propagation falsifies a clause.
+    /// 
+    fn propagate(&self, assignment: &Model, literal: isize) -> Option<(Model, usize)> {
+        if assignment.has(-literal) {
+            return None;
+        }
+        let mut assignment = assignment.clone();
+        assignment.add(literal);
+        let mut trail: Vec<isize> = vec![literal];
+        let mut reduced: HashSet<usize> = HashSet::new();
+
+        let mut head = 0;
+        while head < trail.len() {
+            let assigned = trail[head];
+            head += 1;
+            for &idx in self.occurrences[Cuber::literal_index(-assigned)].iter() {
+                let mut unassigned: Option<isize> = None;
+                let mut num_unassigned = 0;
+                let mut is_satisfied = false;
+                for &x in self.clauses[idx].iter() {
+                    match assignment.satisfies(x) {
+                        SAT::Satisfiable => {
+                            is_satisfied = true;
+                            break;
+                        },
+                        SAT::Unknown => {
+                            unassigned = Some(x);
+                            num_unassigned += 1;
+                        },
+                        SAT::Unsatisfiable => (),
+                    }
+                }
+                if is_satisfied {
+                    continue;
+                }
+                match (num_unassigned, unassigned) {
+                    (0, _) => return None,
+                    (1, Some(unit)) => {
+                        assignment.add(unit);
+                        trail.push(unit);
+                    },
+                    _ => {
+                        reduced.insert(idx);
+                    },
+                }
+            }
+        }
+
+        let reduced = reduced.iter().filter(|&&idx| !self.clauses[idx].iter().any(|&x| assignment.has(x))).count();
+        Some((assignment, reduced))
+    }
+
+    /// Returns the free variables of the unsatisfied clauses.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `assignment` - The current assignment.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<usize>` - The variables, in increasing order.
+    /// 
+    fn free_variables(&self, assignment: &Model) -> Vec<usize> {
+        let mut is_free = vec![false; assignment.len() + 1];
+        for clause in self.clauses.iter() {
+            if clause.iter().any(|&x| assignment.has(x)) {
+                continue;
+            }
+            for &x in clause.iter() {
+                if !assignment.has(-x) {
+                    is_free[x.unsigned_abs()] = true;
+                }
+            }
+        }
+        (1..is_free.len()).filter(|&variable| is_free[variable]).collect()
+    }
+}
\ No newline at end of file
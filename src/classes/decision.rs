@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 pub struct Decision {
     decided_literal: isize,
     
@@ -48,6 +50,20 @@ impl Decision {
     pub fn clear_propagated_literals(&mut self) {
         self.propagated_literals.clear();
     }
+
+    /// Updates the indexes of the clauses that propagated the literals, after some clauses are deleted
+    /// 
+    /// # Arguments
+    /// 
+    /// * `new_indexes` - The new index of every clause that moved, the other indexes are kept
+    /// 
+    pub fn remap_clause_indexes(&mut self, new_indexes: &HashMap<usize, usize>) {
+        for (_, clause_index) in self.propagated_literals.iter_mut() {
+            if let Some(&new_index) = new_indexes.get(clause_index) {
+                *clause_index = new_index;
+            }
+        }
+    }
 }
 
 //impl PartialEq
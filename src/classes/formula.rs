@@ -1,13 +1,25 @@
-use std::collections::HashSet;
+mod encoding;
+mod cardinality;
+mod pseudo_boolean;
+mod opb;
+
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use crate::files;
-use crate::consts::sat::SAT;
-use crate::classes::{clause::Clause, model::Model};
+use crate::consts::{fragment::Fragment, sat::SAT, xor};
+use crate::classes::{clause::Clause, model::Model, xor::Xor};
 
+#[derive(Clone)]
 pub struct Formula {
     clauses: Vec<Clause>,
     num_variables: usize,
     num_clauses: usize,
+    xors: Vec<Xor>,
+    auxiliary_variables: BTreeMap<usize, String>,
+    objective: Option<Vec<(isize, isize)>>,
+    two_cnf: bool,
+    horn: bool,
+    dual_horn: bool,
 
     current_clause_id: usize,
 }
@@ -18,6 +30,12 @@ impl Formula {
             clauses: Vec::new(),
             num_variables: 0,
             num_clauses: 0,
+            xors: Vec::new(),
+            auxiliary_variables: BTreeMap::new(),
+            objective: None,
+            two_cnf: true,
+            horn: true,
+            dual_horn: true,
 
             current_clause_id: 0,
         }
@@ -45,7 +63,7 @@ impl Formula {
     }
 
     /// Loads a string into the formula
-    /// The string must be in DIMACS format
+    /// The string must be in DIMACS format, the lines starting with 'x' are XOR constraints
     /// 
     /// # Arguments
     /// 
@@ -68,6 +86,14 @@ impl Formula {
                 self.num_clauses = problem_line.next().unwrap().parse().unwrap();
                 continue;
             }
+            //if the line is a XOR constraint, add it to the XOR constraints
+            if line.starts_with('x') {
+                let mut xor = Xor::new();
+                if xor.load_string(line.to_string()).is_ok() {
+                    self.xors.push(xor);
+                }
+                continue;
+            }
             //if the line is a clause, add it to the formula
             let mut clause = Clause::new();
             match clause.load_string(line.to_string()) {
@@ -79,6 +105,11 @@ impl Formula {
                 Err(()) => continue,
             };
         }
+        // The problem line of CryptoMiniSat counts the XOR constraints as clauses.
+        if !self.xors.is_empty() {
+            self.num_clauses = self.clauses.len();
+        }
+        self.classify();
     }
 
     /// Adds a clause to the formula
@@ -98,12 +129,233 @@ impl Formula {
                 self.current_clause_id += 1;
                 clause.set_id(self.current_clause_id);
                 self.clauses.push(clause);
+                self.classify_clauses(self.clauses.len() - 1);
                 return Ok(());
             },
             Err(_e) => return Err(()),
         };
     }
 
+    /// Adds a clause to the formula from its literals
+    /// The number of variables is increased to the greatest variable of the clause, even if some variables do not appear in any clause
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literals` - The literals of the clause
+    /// 
+    pub fn add_clause(&mut self, literals: Vec<isize>) {
+        self.num_variables = literals.iter().fold(self.num_variables, |max, literal| max.max(literal.abs() as usize));
+        let mut clause = Clause::new();
+        clause.load_vec(literals);
+        self.current_clause_id += 1;
+        clause.set_id(self.current_clause_id);
+        self.clauses.push(clause);
+        self.num_clauses = self.clauses.len();
+        self.classify_clauses(self.clauses.len() - 1);
+    }
+
+    /// Adds a XOR constraint to the formula from its literals, it is true if an odd number of literals is true
+    /// The number of variables is increased to the greatest variable of the constraint
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literals` - The literals of the constraint
+    /// 
+    pub fn add_xor(&mut self, literals: Vec<isize>) {
+        self.num_variables = literals.iter().fold(self.num_variables, |max, literal| max.max(literal.abs() as usize));
+        let mut xor = Xor::new();
+        xor.load_vec(literals);
+        self.xors.push(xor);
+    }
+
+    /// Returns the XOR constraints
+    /// 
+    /// # Returns
+    /// 
+    /// * `&Vec<Xor>` - The XOR constraints, without the ones encoded as clauses
+    /// 
+    pub fn get_xors(&self) -> &Vec<Xor> {
+        &self.xors
+    }
+
+    /// Finds the XOR constraints encoded as clauses
+    /// A constraint of n variables is encoded by the 2^(n-1) clauses over the same variables whose number of negative
+    /// literals has the same parity, every clause forbids an assignment with the wrong parity
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<Xor>` - The constraints, the clauses are not removed
+    /// 
+    pub fn detect_xors(&self) -> Vec<Xor> {
+        let mut patterns: HashMap<Vec<usize>, [HashSet<usize>; 2]> = HashMap::new();
+        for clause in &self.clauses {
+            let size = clause.literals_len();
+            if size < xor::MIN_DETECTED_SIZE || size > xor::MAX_DETECTED_SIZE || clause.iter_literals().any(|&x| clause.contains_literal(-x)) {
+                continue;
+            }
+            let variables: Vec<usize> = clause.iter_literals().map(|literal| literal.unsigned_abs()).collect();
+            let signs = clause.iter_literals().enumerate().filter(|(_, &literal)| literal < 0).fold(0usize, |signs, (idx, _)| signs | 1 << idx);
+            let parity = signs.count_ones() as usize % 2;
+            patterns.entry(variables).or_insert_with(|| [HashSet::new(), HashSet::new()])[parity].insert(signs);
+        }
+
+        let mut xors: Vec<Xor> = Vec::new();
+        for (variables, signs) in patterns {
+            for (parity, signs) in signs.iter().enumerate() {
+                if signs.len() == 1 << (variables.len() - 1) {
+                    // The clauses with an even number of negative literals forbid the assignments with an even number of true variables.
+                    let mut literals: Vec<isize> = variables.iter().map(|&variable| variable as isize).collect();
+                    if parity == 1 {
+                        literals[0] = -literals[0];
+                    }
+                    let mut xor = Xor::new();
+                    xor.load_vec(literals);
+                    xors.push(xor);
+                }
+            }
+        }
+        xors.sort_by(|a, b| a.get_variables().cmp(b.get_variables()));
+        return xors;
+    }
+
+    /// Replaces the XOR constraints with clauses, for the algorithms that only handle clauses
+    /// A long constraint is cut with new variables, each one is the sum of a part of the constraint
+    pub fn encode_xors(&mut self) {
+        for xor in std::mem::take(&mut self.xors) {
+            let mut variables: Vec<isize> = xor.get_variables().iter().map(|&variable| variable as isize).collect();
+            while variables.len() > xor::ENCODING_CUT {
+                let sum = self.new_auxiliary_variable("XOR cut");
+                let mut part: Vec<isize> = variables.drain(..xor::ENCODING_CUT - 1).collect();
+                // The new variable is equal to the sum of the part, their sum is even.
+                part.push(-sum);
+                variables.insert(0, sum);
+                let mut cut = Xor::new();
+                cut.load_vec(part);
+                cut.to_clauses().into_iter().for_each(|literals| self.push_clause(literals));
+            }
+            // An empty constraint is always true if its parity is even, and always false if it is odd.
+            if variables.is_empty() {
+                if xor.get_parity() {
+                    let contradiction = self.new_auxiliary_variable("contradiction");
+                    self.push_clause(vec![contradiction]);
+                    self.push_clause(vec![-contradiction]);
+                }
+                continue;
+            }
+            // The parity is carried over, the variables are all positive and distinct.
+            let mut rest = Xor::new();
+            rest.load_vec(variables);
+            rest.set_parity(xor.get_parity());
+            rest.to_clauses().into_iter().for_each(|literals| self.push_clause(literals));
+        }
+        self.num_clauses = self.clauses.len();
+        self.classify();
+    }
+
+    /// Adds a clause to the formula from its literals, without updating the statistics
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literals` - The literals of the clause
+    /// 
+    fn push_clause(&mut self, literals: Vec<isize>) {
+        let mut clause = Clause::new();
+        clause.load_vec(literals);
+        self.current_clause_id += 1;
+        clause.set_id(self.current_clause_id);
+        self.clauses.push(clause);
+    }
+
+    /// Adds an auxiliary variable to the formula, it is introduced by an encoding and it is not printed in the model
+    /// 
+    /// # Arguments
+    /// 
+    /// * `origin` - The name of the encoding that introduces the variable
+    /// 
+    /// # Returns
+    /// 
+    /// * `isize` - The new variable
+    /// 
+    pub fn new_auxiliary_variable(&mut self, origin: &str) -> isize {
+        self.num_variables += 1;
+        self.auxiliary_variables.insert(self.num_variables, origin.to_string());
+        return self.num_variables as isize;
+    }
+
+    /// Checks if a variable was introduced by an encoding
+    /// 
+    /// # Arguments
+    /// 
+    /// * `variable` - The variable
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the variable is auxiliary
+    /// 
+    pub fn is_auxiliary_variable(&self, variable: usize) -> bool {
+        self.auxiliary_variables.contains_key(&variable)
+    }
+
+    /// Returns the auxiliary variables
+    /// 
+    /// # Returns
+    /// 
+    /// * `&BTreeMap<usize, String>` - The name of the encoding of every auxiliary variable
+    /// 
+    pub fn get_auxiliary_variables(&self) -> &BTreeMap<usize, String> {
+        &self.auxiliary_variables
+    }
+
+    /// Returns the objective function to minimize
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<&Vec<(isize, isize)>>` - The weight and the literal of every term, None if the formula has no objective
+    /// 
+    pub fn get_objective(&self) -> Option<&Vec<(isize, isize)>> {
+        self.objective.as_ref()
+    }
+
+    /// Sets the objective function to minimize
+    /// The number of variables is increased to the greatest variable of the objective
+    /// 
+    /// # Arguments
+    /// 
+    /// * `objective` - The weight and the literal of every term, None to remove the objective
+    /// 
+    pub fn set_objective(&mut self, objective: Option<Vec<(isize, isize)>>) {
+        if let Some(terms) = &objective {
+            self.num_variables = terms.iter().fold(self.num_variables, |max, &(_, literal)| max.max(literal.unsigned_abs()));
+        }
+        self.objective = objective;
+    }
+
+    /// Computes the value of the objective function in a model, the sum of the weights of the true literals
+    /// 
+    /// # Arguments
+    /// 
+    /// * `model` - The model
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<isize>` - The value, None if the formula has no objective
+    /// 
+    pub fn get_objective_value(&self, model: &Model) -> Option<isize> {
+        self.objective.as_ref().map(|terms| {
+            terms.iter().filter(|&&(_, literal)| model.has(literal)).map(|&(weight, _)| weight).sum()
+        })
+    }
+
+    /// Declares the variables of the formula, even if some of them do not appear in any clause
+    /// 
+    /// # Arguments
+    /// 
+    /// * `num_variables` - The number of variables, it is ignored if the formula already has more variables
+    /// 
+    pub fn reserve_variables(&mut self, num_variables: usize) {
+        self.num_variables = self.num_variables.max(num_variables);
+    }
+
     /// Gets a clause by its id
     ///     
     /// # Arguments
@@ -152,6 +404,40 @@ impl Formula {
         &mut self.clauses
     }
 
+    /// Replaces the clauses of the formula
+    /// The number of variables is kept, so the literals of the new clauses must be variables of the formula
+    /// 
+    /// # Arguments
+    /// 
+    /// * `clauses` - The new clauses
+    /// 
+    pub fn set_clauses(&mut self, clauses: Vec<Clause>) {
+        self.num_clauses = clauses.len();
+        self.clauses = clauses;
+        self.classify();
+    }
+
+    /// Returns the id of the last clause added to the formula
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The id of the last clause
+    /// 
+    pub fn get_current_clause_id(&self) -> usize {
+        self.current_clause_id
+    }
+
+    /// Sets the id of the last clause added to the formula
+    /// The next clauses get an id greater than it
+    /// 
+    /// # Arguments
+    /// 
+    /// * `clause_id` - The id of the last clause
+    /// 
+    pub fn set_current_clause_id(&mut self, clause_id: usize) {
+        self.current_clause_id = clause_id;
+    }
+
     /// Returns the number of variables
     /// 
     /// # Returns
@@ -180,9 +466,58 @@ impl Formula {
         for clause in &self.clauses {
             variables.extend(clause.iter_literals().map(|x| x.abs()));
         }
+        for xor in &self.xors {
+            variables.extend(xor.get_variables().iter().map(|&x| x as isize));
+        }
 
         self.num_variables = variables.len();
         self.num_clauses = self.clauses.len();
+        self.classify();
+    }
+
+    /// Returns the class of the formula
+    /// 
+    /// # Returns
+    /// 
+    /// * `Fragment` - 2-CNF, Horn, dual-Horn or general
+    /// 
+    pub fn get_fragment(&self) -> Fragment {
+        if !self.xors.is_empty() {
+            Fragment::General
+        } else if self.two_cnf {
+            Fragment::TwoCnf
+        } else if self.horn {
+            Fragment::Horn
+        } else if self.dual_horn {
+            Fragment::DualHorn
+        } else {
+            Fragment::General
+        }
+    }
+
+    /// Classifies the formula, it is called when the clauses are replaced
+    /// A formula of binary clauses is 2-CNF, even if it is also Horn. A formula is Horn if every clause has at most one
+    /// positive literal, and dual-Horn if every clause has at most one negative literal. A formula with XOR constraints is general.
+    fn classify(&mut self) {
+        self.two_cnf = true;
+        self.horn = true;
+        self.dual_horn = true;
+        self.classify_clauses(0);
+    }
+
+    /// Updates the class of the formula with the clauses added at the end, a new clause can only make the class more general
+    /// 
+    /// # Arguments
+    /// 
+    /// * `first_clause` - The index of the first new clause
+    /// 
+    fn classify_clauses(&mut self, first_clause: usize) {
+        for clause in self.clauses[first_clause..].iter() {
+            let positives = clause.iter_literals().filter(|&&literal| literal > 0).count();
+            self.two_cnf &= clause.literals_len() <= 2;
+            self.horn &= positives <= 1;
+            self.dual_horn &= clause.literals_len() - positives <= 1;
+        }
     }
 
     /// Returns the literals of the formula
@@ -267,15 +602,62 @@ impl Formula {
             }
             print!(")");
         }
+        for (idx, xor) in self.xors.iter().enumerate() {
+            if !self.clauses.is_empty() || idx != 0 {
+                print!("∧");
+            }
+            let literals: Vec<String> = xor.get_variables().iter().enumerate()
+                .map(|(idx, &variable)| if idx == 0 && !xor.get_parity() { format!("-{}", variable) } else { variable.to_string() })
+                .collect();
+            print!("({})", literals.join("⊕"));
+        }
         println!("\n");
     }
 
     /// Prints the formula in DIMACS format
     pub fn print_dimacs(&self) {
-        println!("p cnf {} {}", self.num_variables, self.num_clauses);
+        print!("{}", self.to_dimacs());
+        println!("\n");
+    }
+
+    /// Returns the formula in DIMACS format
+    /// 
+    /// # Returns
+    /// 
+    /// * `String` - The problem line followed by a line for each clause and a line for each XOR constraint
+    /// 
+    pub fn to_dimacs(&self) -> String {
+        let mut dimacs = format!("p cnf {} {}\n", self.num_variables, self.num_clauses);
         for clause in &self.clauses {
-            println!("{} 0", clause.iter_literals().map(|x| x.to_string()).collect::<Vec<String>>().join(" "));
+            dimacs.push_str(&format!("{} 0\n", clause.iter_literals().map(|x| x.to_string()).collect::<Vec<String>>().join(" ")));
         }
-        println!("\n");
+        for xor in &self.xors {
+            dimacs.push_str(&format!("{}\n", xor));
+        }
+        return dimacs;
+    }
+
+    /// Returns the formula with a set of cubes in iCNF format
+    /// The format has no XOR constraints, they are written as their clauses.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `cubes` - The cubes, each one is written as a line of assumptions
+    /// 
+    /// # Returns
+    /// 
+    /// * `String` - The incremental problem line followed by a line for each clause and a line for each cube
+    /// 
+    pub fn to_icnf(&self, cubes: &Vec<Vec<isize>>) -> String {
+        let mut encoded = self.clone();
+        encoded.encode_xors();
+        let mut icnf = String::from("p inccnf\n");
+        for clause in &encoded.clauses {
+            icnf.push_str(&format!("{} 0\n", clause.iter_literals().map(|x| x.to_string()).collect::<Vec<String>>().join(" ")));
+        }
+        for cube in cubes {
+            icnf.push_str(&format!("a {}0\n", cube.iter().map(|x| format!("{} ", x)).collect::<String>()));
+        }
+        return icnf;
     }
 }
\ No newline at end of file
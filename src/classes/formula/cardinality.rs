@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use crate::consts::encoding::Encoding;
+use super::Formula;
+
+/// A wire of a sorting network, the outputs of a comparator are the disjunction and the conjunction of its inputs.
+#[derive(Clone, Copy)]
+enum Wire {
+    False,
+    Literal(isize),
+    Or(usize, usize),
+    And(usize, usize),
+}
+
+impl Formula {
+
+    /// Encodes Σ l <= k by forbidding every set of k + 1 literals, with no auxiliary variables.
+    /// The number of clauses is the binomial coefficient of n and k + 1, it is the usual encoding of at most one.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literals` - The literals, there are more than k.
+    /// * `k` - The maximum number of true literals.
+    /// 
+    pub(super) fn encode_pairwise(&mut self, literals: &Vec<isize>, k: usize) {
+        let mut subset: Vec<usize> = (0..=k).collect();
+        loop {
+            self.push_clause(subset.iter().map(|&idx| -literals[idx]).collect());
+
+            // The next subset in lexicographic order.
+            let mut position = k + 1;
+            while position > 0 && subset[position - 1] == literals.len() - (k + 1) + (position - 1) {
+                position -= 1;
+            }
+            if position == 0 {
+                return;
+            }
+            subset[position - 1] += 1;
+            for next in position..=k {
+                subset[next] = subset[next - 1] + 1;
+            }
+        }
+    }
+
+    /// Encodes Σ l <= k with the sequential counter of Sinz, the register s(i, j) is true if at least j + 1 of the
+    /// first i + 1 literals are true. It has O(n·k) clauses and auxiliary variables.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literals` - The literals, there are more than k.
+    /// * `k` - The maximum number of true literals.
+    /// 
+    pub(super) fn encode_sequential_counter(&mut self, literals: &Vec<isize>, k: usize) {
+        let name = Encoding::SequentialCounter.to_string();
+        let n = literals.len();
+        let registers: Vec<Vec<isize>> = (0..n - 1).map(|_| (0..k).map(|_| self.new_auxiliary_variable(&name)).collect()).collect();
+
+        self.push_clause(vec![-literals[0], registers[0][0]]);
+        for j in 1..k {
+            self.push_clause(vec![-registers[0][j]]);
+        }
+        for i in 1..n - 1 {
+            self.push_clause(vec![-literals[i], registers[i][0]]);
+            self.push_clause(vec![-registers[i - 1][0], registers[i][0]]);
+            for j in 1..k {
+                self.push_clause(vec![-literals[i], -registers[i - 1][j - 1], registers[i][j]]);
+                self.push_clause(vec![-registers[i - 1][j], registers[i][j]]);
+            }
+            // The literal cannot be true if k literals before it are already true.
+            self.push_clause(vec![-literals[i], -registers[i - 1][k - 1]]);
+        }
+        self.push_clause(vec![-literals[n - 1], -registers[n - 2][k - 1]]);
+    }
+
+    /// Encodes Σ l <= k with the totalizer of Bailleux and Boufkhad, a binary tree whose nodes count the true literals
+    /// of their leaves in unary. The counters are cut at k + 1, and the (k + 1)-th output of the root is false.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literals` - The literals, there are more than k.
+    /// * `k` - The maximum number of true literals.
+    /// 
+    pub(super) fn encode_totalizer(&mut self, literals: &Vec<isize>, k: usize) {
+        let outputs = self.totalizer_node(literals, k);
+        self.push_clause(vec![-outputs[k]]);
+    }
+
+    /// Builds a node of the totalizer, the output j is true if at least j + 1 of the literals are true.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literals` - The literals of the leaves of the node.
+    /// * `k` - The maximum number of true literals of the constraint.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<isize>` - The outputs, at most k + 1.
+    /// 
+    fn totalizer_node(&mut self, literals: &[isize], k: usize) -> Vec<isize> {
+        if literals.len() == 1 {
+            return vec![literals[0]];
+        }
+        let left = self.totalizer_node(&literals[..literals.len() / 2], k);
+        let right = self.totalizer_node(&literals[literals.len() / 2..], k);
+
+        let name = Encoding::Totalizer.to_string();
+        let outputs: Vec<isize> = (0..literals.len().min(k + 1)).map(|_| self.new_auxiliary_variable(&name)).collect();
+        for i in 0..=left.len() {
+            for j in 0..=right.len() {
+                if i + j == 0 || i + j > outputs.len() {
+                    continue;
+                }
+                // i true literals on the left and j on the right are at least i + j.
+                let mut clause: Vec<isize> = Vec::new();
+                if i > 0 {
+                    clause.push(-left[i - 1]);
+                }
+                if j > 0 {
+                    clause.push(-right[j - 1]);
+                }
+                clause.push(outputs[i + j - 1]);
+                self.push_clause(clause);
+            }
+        }
+        return outputs;
+    }
+
+    /// Encodes Σ l <= k with a cardinality network: the literals are sorted by the odd-even merge sorting network of
+    /// Batcher, and the (k + 1)-th output is false. Only the comparators that the output depends on are encoded, and
+    /// only in the direction that counts the true literals.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literals` - The literals, there are more than k.
+    /// * `k` - The maximum number of true literals.
+    /// 
+    pub(super) fn encode_cardinality_network(&mut self, literals: &Vec<isize>, k: usize) {
+        let size = literals.len().next_power_of_two();
+        let mut nodes: Vec<Wire> = vec![Wire::False];
+        nodes.extend(literals.iter().map(|&literal| Wire::Literal(literal)));
+        let mut wires: Vec<usize> = (0..size).map(|idx| if idx < literals.len() { idx + 1 } else { 0 }).collect();
+
+        let mut width = 1;
+        while width < size {
+            let mut distance = width;
+            while distance >= 1 {
+                let mut start = distance % width;
+                while start + distance < size {
+                    for offset in 0..distance.min(size - start - distance) {
+                        let (first, second) = (start + offset, start + offset + distance);
+                        if first / (2 * width) != second / (2 * width) {
+                            continue;
+                        }
+                        // The greater value goes to the first wire, so the outputs are sorted from the true ones.
+                        let (a, b) = (wires[first], wires[second]);
+                        match (nodes[a], nodes[b]) {
+                            (Wire::False, _) => (wires[first], wires[second]) = (b, 0),
+                            (_, Wire::False) => (wires[first], wires[second]) = (a, 0),
+                            _ => {
+                                nodes.push(Wire::Or(a, b));
+                                nodes.push(Wire::And(a, b));
+                                (wires[first], wires[second]) = (nodes.len() - 2, nodes.len() - 1);
+                            },
+                        }
+                    }
+                    start += 2 * distance;
+                }
+                distance /= 2;
+            }
+            width *= 2;
+        }
+
+        let mut literals_of_nodes: HashMap<usize, isize> = HashMap::new();
+        if let Some(output) = self.network_literal(&nodes, wires[k], &mut literals_of_nodes) {
+            self.push_clause(vec![-output]);
+        }
+    }
+
+    /// Returns the literal of a wire of the sorting network, the clauses of its comparators are encoded the first time.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nodes` - The wires of the network.
+    /// * `node` - The wire.
+    /// * `literals_of_nodes` - The literals of the wires already encoded.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<isize>` - The literal, None if the wire is always false.
+    /// 
+    fn network_literal(&mut self, nodes: &Vec<Wire>, node: usize, literals_of_nodes: &mut HashMap<usize, isize>) -> Option<isize> {
+        if let Some(&literal) = literals_of_nodes.get(&node) {
+            return Some(literal);
+        }
+        let literal = match nodes[node] {
+            Wire::False => return None,
+            Wire::Literal(literal) => literal,
+            Wire::Or(a, b) | Wire::And(a, b) => {
+                let a = self.network_literal(nodes, a, literals_of_nodes).unwrap();
+                let b = self.network_literal(nodes, b, literals_of_nodes).unwrap();
+                let output = self.new_auxiliary_variable(&Encoding::CardinalityNetwork.to_string());
+                match nodes[node] {
+                    Wire::Or(_, _) => {
+                        self.push_clause(vec![-a, output]);
+                        self.push_clause(vec![-b, output]);
+                    },
+                    _ => self.push_clause(vec![-a, -b, output]),
+                }
+                output
+            },
+        };
+        literals_of_nodes.insert(node, literal);
+        return Some(literal);
+    }
+
+}
\ No newline at end of file
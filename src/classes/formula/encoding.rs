@@ -0,0 +1,230 @@
+use std::collections::BTreeMap;
+
+use crate::consts::encoding::{Comparator, Encoding};
+use super::Formula;
+
+/// A constraint Σ w·l <= bound with positive weights, the literals with a weight greater than the bound are false.
+struct Normalized {
+    false_literals: Vec<isize>,
+    terms: Vec<(usize, isize)>,
+    bound: isize,
+}
+
+impl Formula {
+
+    /// Adds the constraint that at most k literals are true
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literals` - The literals
+    /// * `k` - The maximum number of true literals
+    /// * `encoding` - The encoding of the constraint
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<(), ()>` - Err(()) if a literal is 0
+    /// 
+    pub fn add_at_most(&mut self, literals: &Vec<isize>, k: usize, encoding: Encoding) -> Result<(), ()> {
+        let terms: Vec<(isize, isize)> = literals.iter().map(|&literal| (1, literal)).collect();
+        return self.add_pseudo_boolean(&terms, Comparator::AtMost, k as isize, encoding);
+    }
+
+    /// Adds the constraint that at least k literals are true
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literals` - The literals
+    /// * `k` - The minimum number of true literals
+    /// * `encoding` - The encoding of the constraint
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<(), ()>` - Err(()) if a literal is 0
+    /// 
+    pub fn add_at_least(&mut self, literals: &Vec<isize>, k: usize, encoding: Encoding) -> Result<(), ()> {
+        let terms: Vec<(isize, isize)> = literals.iter().map(|&literal| (1, literal)).collect();
+        return self.add_pseudo_boolean(&terms, Comparator::AtLeast, k as isize, encoding);
+    }
+
+    /// Adds the constraint that exactly k literals are true
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literals` - The literals
+    /// * `k` - The number of true literals
+    /// * `encoding` - The encoding of the constraint
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<(), ()>` - Err(()) if a literal is 0
+    /// 
+    pub fn add_exactly(&mut self, literals: &Vec<isize>, k: usize, encoding: Encoding) -> Result<(), ()> {
+        let terms: Vec<(isize, isize)> = literals.iter().map(|&literal| (1, literal)).collect();
+        return self.add_pseudo_boolean(&terms, Comparator::Exactly, k as isize, encoding);
+    }
+
+    /// Adds a linear pseudo-Boolean constraint Σ w·l (comparator) bound, encoded as clauses
+    /// The auxiliary variables of the encoding are added to the formula, they are not printed in the model.
+    /// The weights can be negative and a variable can appear in more terms, the constraint is normalized first:
+    /// the cardinality encodings are used only if all the weights are equal after the normalization.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `terms` - The weight and the literal of every term
+    /// * `comparator` - At most, at least or exactly the bound
+    /// * `bound` - The bound
+    /// * `encoding` - The encoding of the constraint
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<(), ()>` - Err(()) if a literal is 0, or if the weights are different and the encoding only handles cardinality constraints,
+    /// the formula is not changed
+    /// 
+    pub fn add_pseudo_boolean(&mut self, terms: &Vec<(isize, isize)>, comparator: Comparator, bound: isize, encoding: Encoding) -> Result<(), ()> {
+        let first_clause = self.clauses.len();
+        self.encode_pseudo_boolean(terms, comparator, bound, encoding)?;
+        self.num_clauses = self.clauses.len();
+        self.classify_clauses(first_clause);
+        return Ok(());
+    }
+
+    /// Adds a linear pseudo-Boolean constraint that holds only if a selector literal is true
+    /// Every clause of the encoding gets the negation of the selector, so the constraint is enabled by assuming the selector
+    /// and it is disabled forever by the unit clause of its negation.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `selector` - The literal that enables the constraint
+    /// * `terms` - The weight and the literal of every term
+    /// * `comparator` - At most, at least or exactly the bound
+    /// * `bound` - The bound
+    /// * `encoding` - The encoding of the constraint
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<(), ()>` - Err(()) in the same cases of `add_pseudo_boolean`, or if the selector is 0
+    /// 
+    pub fn add_conditional_pseudo_boolean(&mut self, selector: isize, terms: &Vec<(isize, isize)>, comparator: Comparator, bound: isize, encoding: Encoding) -> Result<(), ()> {
+        if selector == 0 {
+            return Err(());
+        }
+        let first_clause = self.clauses.len();
+        self.encode_pseudo_boolean(terms, comparator, bound, encoding)?;
+        self.clauses[first_clause..].iter_mut().for_each(|clause| clause.add_literal(-selector));
+        self.num_variables = self.num_variables.max(selector.unsigned_abs());
+        self.num_clauses = self.clauses.len();
+        self.classify_clauses(first_clause);
+        return Ok(());
+    }
+
+    /// Encodes a linear pseudo-Boolean constraint as clauses, the number of clauses and the fragment are not updated.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `terms` - The weight and the literal of every term
+    /// * `comparator` - At most, at least or exactly the bound
+    /// * `bound` - The bound
+    /// * `encoding` - The encoding of the constraint
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<(), ()>` - The same errors of `add_pseudo_boolean`
+    /// 
+    fn encode_pseudo_boolean(&mut self, terms: &Vec<(isize, isize)>, comparator: Comparator, bound: isize, encoding: Encoding) -> Result<(), ()> {
+        if terms.iter().any(|&(_, literal)| literal == 0) {
+            return Err(());
+        }
+
+        let negated: Vec<(isize, isize)> = terms.iter().map(|&(weight, literal)| (-weight, literal)).collect();
+        // Σ w·l >= bound is Σ -w·l <= -bound.
+        let constraints = match comparator {
+            Comparator::AtMost => vec![Self::normalize(terms, bound)],
+            Comparator::AtLeast => vec![Self::normalize(&negated, -bound)],
+            Comparator::Exactly => vec![Self::normalize(terms, bound), Self::normalize(&negated, -bound)],
+        };
+        let is_cardinality = |constraint: &Normalized| constraint.terms.iter().all(|&(weight, _)| weight == constraint.terms[0].0);
+        if !encoding.is_pseudo_boolean() && !constraints.iter().all(is_cardinality) {
+            return Err(());
+        }
+
+        self.num_variables = terms.iter().fold(self.num_variables, |max, &(_, literal)| max.max(literal.unsigned_abs()));
+        for constraint in constraints {
+            if constraint.bound < 0 {
+                // The constraint is false, the formula gets a contradiction.
+                let contradiction = self.new_auxiliary_variable("contradiction");
+                self.push_clause(vec![contradiction]);
+                self.push_clause(vec![-contradiction]);
+                continue;
+            }
+            constraint.false_literals.iter().for_each(|&literal| self.push_clause(vec![-literal]));
+            let total: usize = constraint.terms.iter().map(|&(weight, _)| weight).sum();
+            if total as isize <= constraint.bound {
+                continue;
+            }
+
+            if !encoding.is_pseudo_boolean() {
+                let literals: Vec<isize> = constraint.terms.iter().map(|&(_, literal)| literal).collect();
+                let k = constraint.bound as usize / constraint.terms[0].0;
+                match encoding {
+                    Encoding::Pairwise => self.encode_pairwise(&literals, k),
+                    Encoding::SequentialCounter => self.encode_sequential_counter(&literals, k),
+                    Encoding::Totalizer => self.encode_totalizer(&literals, k),
+                    _ => self.encode_cardinality_network(&literals, k),
+                }
+            } else {
+                match encoding {
+                    Encoding::Adder => self.encode_adder(&constraint.terms, constraint.bound as usize),
+                    _ => self.encode_bdd(&constraint.terms, constraint.bound as usize),
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    /// Normalizes a constraint Σ w·l <= bound, so that every variable has a single term with a positive weight
+    /// A term w·-x is w - w·x, and a term with a negative weight w·x is w + |w|·-x.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `terms` - The weight and the literal of every term
+    /// * `bound` - The bound
+    /// 
+    /// # Returns
+    /// 
+    /// * `Normalized` - The normalized constraint
+    /// 
+    fn normalize(terms: &Vec<(isize, isize)>, bound: isize) -> Normalized {
+        let mut weights: BTreeMap<usize, isize> = BTreeMap::new();
+        let mut bound = bound;
+        for &(weight, literal) in terms.iter() {
+            let variable_weight = weights.entry(literal.unsigned_abs()).or_insert(0);
+            if literal > 0 {
+                *variable_weight += weight;
+            } else {
+                *variable_weight -= weight;
+                bound -= weight;
+            }
+        }
+
+        let mut positive_terms: Vec<(isize, isize)> = Vec::new();
+        for (variable, weight) in weights {
+            let variable = variable as isize;
+            match weight {
+                0 => (),
+                weight if weight > 0 => positive_terms.push((weight, variable)),
+                weight => {
+                    bound -= weight;
+                    positive_terms.push((-weight, -variable));
+                },
+            }
+        }
+
+        let (false_literals, terms): (Vec<(isize, isize)>, Vec<(isize, isize)>) = positive_terms.into_iter().partition(|&(weight, _)| weight > bound);
+        return Normalized {
+            false_literals: false_literals.into_iter().map(|(_, literal)| literal).collect(),
+            terms: terms.into_iter().map(|(weight, literal)| (weight as usize, literal)).collect(),
+            bound,
+        };
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,108 @@
+use crate::files;
+use crate::consts::encoding::{self, Comparator, Encoding};
+use super::Formula;
+
+impl Formula {
+
+    /// Loads an OPB file into the formula
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file
+    /// * `encoding` - The encoding of the constraints
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), std::io::Error>` - The result of the operation, an error of kind InvalidData if the file is not a valid OPB file
+    ///
+    pub fn load_opb_file(&mut self, path: &str, encoding: Encoding) -> Result<(), std::io::Error> {
+        let contents = files::read_file(path)?;
+        return self.load_opb(contents, encoding)
+            .map_err(|line| std::io::Error::new(std::io::ErrorKind::InvalidData, format!("invalid OPB statement: {}", line)));
+    }
+
+    /// Loads a string in OPB format into the formula
+    /// The lines starting with '*' are comments, the variable x<n> is the variable n of the formula and ~x<n> is its negation.
+    /// Every statement ends with ';', the first one can be the objective function "min: <terms>", the others are linear
+    /// constraints "<terms> >= <bound>" or "<terms> = <bound>", each term is an integer weight followed by a literal.
+    /// The constraints are encoded as clauses with the encoding, the constraints with different weights are encoded with
+    /// the fallback encoding if the encoding only handles cardinality constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `opb_string` - The string to load
+    /// * `encoding` - The encoding of the constraints
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), String>` - The result of the operation, the first invalid statement if there is one
+    ///
+    pub fn load_opb(&mut self, opb_string: String, encoding: Encoding) -> Result<(), String> {
+        let mut statements = String::new();
+        for line in opb_string.lines() {
+            if line.starts_with('*') {
+                // The header declares the number of variables, some of them could appear in no constraint.
+                let mut header = line.split_whitespace();
+                while let Some(token) = header.next() {
+                    if token == "#variable=" {
+                        if let Some(num_variables) = header.next().and_then(|value| value.parse().ok()) {
+                            self.reserve_variables(num_variables);
+                        }
+                    }
+                }
+                continue;
+            }
+            statements.push_str(line);
+            statements.push(' ');
+        }
+
+        for statement in statements.split(';').map(|statement| statement.trim()).filter(|statement| !statement.is_empty()) {
+            if let Some(objective) = statement.strip_prefix("min:") {
+                let terms = Self::parse_opb_terms(objective).ok_or(statement.to_string())?;
+                self.set_objective(Some(terms));
+                continue;
+            }
+
+            let mut tokens: Vec<&str> = statement.split_whitespace().collect();
+            let bound: isize = tokens.pop().and_then(|bound| bound.parse().ok()).ok_or(statement.to_string())?;
+            let comparator = match tokens.pop() {
+                Some(">=") => Comparator::AtLeast,
+                Some("<=") => Comparator::AtMost,
+                Some("=") => Comparator::Exactly,
+                _ => return Err(statement.to_string()),
+            };
+            let terms = Self::parse_opb_terms(&tokens.join(" ")).ok_or(statement.to_string())?;
+            if self.add_pseudo_boolean(&terms, comparator, bound, encoding).is_err() {
+                self.add_pseudo_boolean(&terms, comparator, bound, encoding::PSEUDO_BOOLEAN_FALLBACK).map_err(|()| statement.to_string())?;
+            }
+        }
+        return Ok(());
+    }
+
+    /// Parses the linear terms of an OPB statement, the non-linear terms are not supported
+    ///
+    /// # Arguments
+    ///
+    /// * `terms_string` - The terms, an integer weight and a literal for each term
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Vec<(isize, isize)>>` - The weight and the literal of every term, None if the terms are not valid
+    ///
+    fn parse_opb_terms(terms_string: &str) -> Option<Vec<(isize, isize)>> {
+        let tokens: Vec<&str> = terms_string.split_whitespace().collect();
+        if tokens.len() % 2 != 0 {
+            return None;
+        }
+        return tokens.chunks(2).map(|term| {
+            let weight: isize = term[0].parse().ok()?;
+            let (negated, name) = match term[1].strip_prefix('~') {
+                Some(name) => (true, name),
+                None => (false, term[1]),
+            };
+            let variable: isize = name.strip_prefix('x')?.parse().ok().filter(|&variable: &isize| variable > 0)?;
+            Some((weight, if negated { -variable } else { variable }))
+        }).collect();
+    }
+
+}
\ No newline at end of file
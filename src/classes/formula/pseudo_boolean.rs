@@ -0,0 +1,172 @@
+use std::collections::VecDeque;
+
+use crate::consts::encoding::Encoding;
+use super::Formula;
+
+/// A node of a BDD, the constant nodes have no variable.
+#[derive(Clone, Copy, PartialEq)]
+enum Node {
+    True,
+    False,
+    Literal(isize),
+}
+
+impl Formula {
+
+    /// Encodes Σ w·l <= bound with binary adders: every weight is split in its bits, the literals of the same bit are
+    /// summed by full and half adders whose carries go to the next bit, then the binary sum is compared with the bound.
+    /// The adders are defined in both directions, so the sum is exact.
+    ///
+    /// # Arguments
+    ///
+    /// * `terms` - The positive weight and the literal of every term.
+    /// * `bound` - The bound.
+    ///
+    pub(super) fn encode_adder(&mut self, terms: &Vec<(usize, isize)>, bound: usize) {
+        let name = Encoding::Adder.to_string();
+        let mut buckets: Vec<VecDeque<isize>> = Vec::new();
+        for &(weight, literal) in terms.iter() {
+            for bit in 0..usize::BITS as usize {
+                if weight >> bit & 1 == 1 {
+                    if buckets.len() <= bit {
+                        buckets.resize(bit + 1, VecDeque::new());
+                    }
+                    buckets[bit].push_back(literal);
+                }
+            }
+        }
+
+        let mut sum: Vec<Option<isize>> = Vec::new();
+        let mut bit = 0;
+        while bit < buckets.len() {
+            while buckets[bit].len() >= 2 {
+                let inputs: Vec<isize> = (0..buckets[bit].len().min(3)).map(|_| buckets[bit].pop_front().unwrap()).collect();
+                let digit = self.new_auxiliary_variable(&name);
+                let carry = self.new_auxiliary_variable(&name);
+                self.push_definition(&inputs, digit, |ones| ones % 2 == 1);
+                self.push_definition(&inputs, carry, |ones| ones >= 2);
+                buckets[bit].push_back(digit);
+                if buckets.len() <= bit + 1 {
+                    buckets.push(VecDeque::new());
+                }
+                buckets[bit + 1].push_back(carry);
+            }
+            sum.push(buckets[bit].pop_front());
+            bit += 1;
+        }
+
+        // The sum is greater than the bound if it has a one where the bound has a zero, and all the ones of the bound before it.
+        for i in 0..sum.len() {
+            let digit = match sum[i] {
+                Some(digit) if bound >> i & 1 == 0 => digit,
+                _ => continue,
+            };
+            let mut clause: Vec<isize> = vec![-digit];
+            let mut is_satisfied = false;
+            for j in (i + 1)..usize::BITS as usize {
+                if bound >> j & 1 == 1 {
+                    match sum.get(j).cloned().flatten() {
+                        Some(higher) => clause.push(-higher),
+                        None => is_satisfied = true,
+                    }
+                }
+            }
+            if !is_satisfied {
+                self.push_clause(clause);
+            }
+        }
+    }
+
+    /// Adds the clauses that define a variable as a function of the number of true inputs, one clause for each assignment of the inputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - The inputs.
+    /// * `output` - The defined variable.
+    /// * `function` - The value of the output for a number of true inputs.
+    ///
+    fn push_definition(&mut self, inputs: &Vec<isize>, output: isize, function: fn(u32) -> bool) {
+        for assignment in 0..(1usize << inputs.len()) {
+            let mut clause: Vec<isize> = inputs.iter().enumerate()
+                .map(|(idx, &input)| if assignment >> idx & 1 == 1 { -input } else { input })
+                .collect();
+            clause.push(if function(assignment.count_ones()) { output } else { -output });
+            self.push_clause(clause);
+        }
+    }
+
+    /// Encodes Σ w·l <= bound with a reduced ordered BDD, built from the greatest weights.
+    /// A node of the level i is true if the terms from i are at most its budget, and its two children are the budgets
+    /// with and without the weight of the term i. A node is the same for an interval of budgets, so the nodes are
+    /// shared by the intervals of Abío et al. and the BDD has a polynomial size when the weights are small.
+    ///
+    /// # Arguments
+    ///
+    /// * `terms` - The positive weight and the literal of every term.
+    /// * `bound` - The bound.
+    ///
+    pub(super) fn encode_bdd(&mut self, terms: &Vec<(usize, isize)>, bound: usize) {
+        let mut terms = terms.clone();
+        terms.sort_by(|a, b| b.0.cmp(&a.0));
+        let mut suffix_sums: Vec<usize> = vec![0; terms.len() + 1];
+        for level in (0..terms.len()).rev() {
+            suffix_sums[level] = suffix_sums[level + 1] + terms[level].0;
+        }
+
+        let mut levels: Vec<Vec<(isize, isize, Node)>> = vec![Vec::new(); terms.len()];
+        match self.bdd_node(&terms, &suffix_sums, 0, bound as isize, &mut levels).2 {
+            Node::Literal(root) => self.push_clause(vec![root]),
+            _ => (),
+        }
+    }
+
+    /// Returns the node of a level of the BDD for a budget, it is built if no node of the level has the budget in its interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `terms` - The terms, sorted from the greatest weight.
+    /// * `suffix_sums` - The sum of the weights of the terms from every level.
+    /// * `level` - The level of the node.
+    /// * `budget` - The budget of the node.
+    /// * `levels` - The interval and the node of every node already built, by level.
+    ///
+    /// # Returns
+    ///
+    /// * `(isize, isize, Node)` - The interval of the budgets of the node and the node.
+    ///
+    fn bdd_node(&mut self, terms: &Vec<(usize, isize)>, suffix_sums: &Vec<usize>, level: usize, budget: isize, levels: &mut Vec<Vec<(isize, isize, Node)>>) -> (isize, isize, Node) {
+        if budget < 0 {
+            return (isize::MIN, -1, Node::False);
+        }
+        if suffix_sums[level] as isize <= budget {
+            return (suffix_sums[level] as isize, isize::MAX, Node::True);
+        }
+        if let Some(&found) = levels[level].iter().find(|&&(lower, upper, _)| lower <= budget && budget <= upper) {
+            return found;
+        }
+
+        let (weight, literal) = terms[level];
+        let (high_lower, high_upper, high) = self.bdd_node(terms, suffix_sums, level + 1, budget - weight as isize, levels);
+        let (low_lower, low_upper, low) = self.bdd_node(terms, suffix_sums, level + 1, budget, levels);
+        let lower = high_lower.saturating_add(weight as isize).max(low_lower);
+        let upper = high_upper.saturating_add(weight as isize).min(low_upper);
+
+        let node = if high == low {
+            high
+        } else {
+            let node = self.new_auxiliary_variable(&Encoding::Bdd.to_string());
+            // The node implies its child of the value of the literal.
+            for (child, branch) in [(high, -literal), (low, literal)] {
+                match child {
+                    Node::True => (),
+                    Node::False => self.push_clause(vec![-node, branch]),
+                    Node::Literal(child) => self.push_clause(vec![-node, branch, child]),
+                }
+            }
+            Node::Literal(node)
+        };
+        levels[level].push((lower, upper, node));
+        return (lower, upper, node);
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,173 @@
+use crate::consts::sat::SAT;
+use crate::classes::{model::Model, xor::Xor};
+
+/// Gauss-Jordan elimination of a system of XOR constraints, the rows of the matrix are bitsets over the columns.
+pub struct Gauss {
+    xors: Vec<Xor>,
+    columns: Vec<usize>,
+    rows: Vec<Vec<u64>>,
+    parities: Vec<bool>,
+}
+
+impl Gauss {
+    pub fn new() -> Gauss {
+        Gauss {
+            xors: Vec::new(),
+            columns: Vec::new(),
+            rows: Vec::new(),
+            parities: Vec::new(),
+        }
+    }
+
+    /// Load the XOR constraints, a column is created for every variable of the constraints.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `xors` - The XOR constraints.
+    /// 
+    pub fn load(&mut self, xors: Vec<Xor>) {
+        let mut columns: Vec<usize> = xors.iter().flat_map(|xor| xor.get_variables().iter().cloned()).collect();
+        columns.sort();
+        columns.dedup();
+
+        let words = (columns.len() + 63) / 64;
+        self.rows = xors.iter().map(|xor| {
+            let mut row = vec![0u64; words];
+            for variable in xor.get_variables() {
+                let column = columns.binary_search(variable).unwrap();
+                row[column / 64] |= 1 << (column % 64);
+            }
+            row
+        }).collect();
+        self.parities = xors.iter().map(|xor| xor.get_parity()).collect();
+        self.columns = columns;
+        self.xors = xors;
+    }
+
+    /// Returns the number of XOR constraints.
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of rows of the matrix.
+    /// 
+    pub fn len(&self) -> usize {
+        self.xors.len()
+    }
+
+    /// Returns true if there are no XOR constraints.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the matrix has no rows.
+    /// 
+    pub fn is_empty(&self) -> bool {
+        self.xors.is_empty()
+    }
+
+    /// Check if every XOR constraint is satisfied by the model.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `model` - The model to check.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - False if a constraint is falsified or has an unassigned variable.
+    /// 
+    pub fn is_satisfied(&self, model: &Model) -> bool {
+        self.xors.iter().all(|xor| xor.is_satisfied_by_model(model) == SAT::Satisfiable)
+    }
+
+    /// Returns an unassigned variable of the XOR constraints, so that the constraints are decided even if the clauses are satisfied.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `model` - The current model.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<usize>` - The variable, None if every variable of the constraints is assigned.
+    /// 
+    pub fn get_unassigned_variable(&self, model: &Model) -> Option<usize> {
+        self.columns.iter().find(|&&variable| !model.has_abs(variable)).cloned()
+    }
+
+    /// Propagate the XOR constraints under the model.
+    /// The columns of the unassigned variables are eliminated, so a row with a single unassigned variable implies it,
+    /// and a row without unassigned variables and with the wrong parity is a conflict. Every row is a sum of the
+    /// constraints, so it is explained by the clause that forbids the current values of its assigned variables.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `model` - The current model.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<Vec<Vec<isize>>, Vec<isize>>` - The reason clauses of the implied literals, the implied literal is the first one,
+    /// or the falsified clause of a conflict.
+    /// 
+    pub fn propagate(&self, model: &Model) -> Result<Vec<Vec<isize>>, Vec<isize>> {
+        let words = (self.columns.len() + 63) / 64;
+        let mut unassigned = vec![0u64; words];
+        let mut positive = vec![0u64; words];
+        for (column, &variable) in self.columns.iter().enumerate() {
+            match model.satisfies(variable as isize) {
+                SAT::Satisfiable => positive[column / 64] |= 1 << (column % 64),
+                SAT::Unsatisfiable => (),
+                SAT::Unknown => unassigned[column / 64] |= 1 << (column % 64),
+            }
+        }
+
+        let mut rows = self.rows.clone();
+        let mut parities = self.parities.clone();
+        let mut pivot_row = 0;
+        for column in 0..self.columns.len() {
+            if unassigned[column / 64] >> (column % 64) & 1 == 0 {
+                continue;
+            }
+            let found = match (pivot_row..rows.len()).find(|&row| rows[row][column / 64] >> (column % 64) & 1 == 1) {
+                Some(found) => found,
+                None => continue,
+            };
+            rows.swap(pivot_row, found);
+            parities.swap(pivot_row, found);
+            let pivot = rows[pivot_row].clone();
+            for row in 0..rows.len() {
+                if row != pivot_row && rows[row][column / 64] >> (column % 64) & 1 == 1 {
+                    rows[row].iter_mut().zip(pivot.iter()).for_each(|(word, pivot_word)| *word ^= pivot_word);
+                    parities[row] ^= parities[pivot_row];
+                }
+            }
+            pivot_row += 1;
+        }
+
+        let mut reasons: Vec<Vec<isize>> = Vec::new();
+        for (row, &parity) in rows.iter().zip(parities.iter()) {
+            let free = row.iter().zip(unassigned.iter()).map(|(word, free)| (word & free).count_ones()).sum::<u32>();
+            let ones = row.iter().zip(positive.iter()).map(|(word, ones)| (word & ones).count_ones()).sum::<u32>();
+            // The parity that the unassigned variables of the row must have.
+            let remaining = parity != (ones % 2 == 1);
+            if free > 1 || (free == 0 && !remaining) {
+                continue;
+            }
+
+            let mut clause: Vec<isize> = Vec::new();
+            for (column, &variable) in self.columns.iter().enumerate() {
+                if row[column / 64] >> (column % 64) & 1 == 0 {
+                    continue;
+                }
+                let variable = variable as isize;
+                match model.satisfies(variable) {
+                    SAT::Satisfiable => clause.push(-variable),
+                    SAT::Unsatisfiable => clause.push(variable),
+                    SAT::Unknown => clause.insert(0, if remaining { variable } else { -variable }),
+                }
+            }
+            if free == 0 {
+                return Err(clause);
+            }
+            reasons.push(clause);
+        }
+        return Ok(reasons);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,124 @@
+pub mod vsids;
+pub mod vmtf;
+pub mod chb;
+pub mod jeroslow_wang;
+pub mod dlis;
+pub mod moms;
+
+use crate::classes::{clause::Clause, model::Model};
+
+/// A decision heuristic of the solver
+/// The solver asks the heuristic for the literal to branch on, and notifies it of every assignment and conflict.
+/// It is `Send` so the solvers of a portfolio can run on separate threads.
+pub trait DecisionHeuristic: Send {
+    /// Returns the name of the heuristic
+    /// 
+    /// # Returns
+    /// 
+    /// * `&'static str` - The name, one of the names in `consts::heuristics::HEURISTICS`
+    /// 
+    fn name(&self) -> &'static str;
+
+    /// Clears the scores of the heuristic
+    fn reset(&mut self);
+
+    /// Prepares the heuristic for a formula, the scores are kept if the number of variables does not change
+    /// 
+    /// # Arguments
+    /// 
+    /// * `num_variables` - The number of variables of the formula
+    /// 
+    fn resize(&mut self, num_variables: usize);
+
+    /// Notifies the heuristic that a literal is assigned, by a decision or a propagation
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literal` - The assigned literal
+    /// 
+    fn on_assign(&mut self, _literal: isize) {}
+
+    /// Notifies the heuristic that a literal is unassigned by a backjump
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literal` - The unassigned literal
+    /// 
+    fn on_unassign(&mut self, _literal: isize) {}
+
+    /// Notifies the heuristic of a conflict
+    /// 
+    /// # Arguments
+    /// 
+    /// * `conflict_clause` - The falsified clause
+    /// * `learned_clause` - The clause learned from the conflict
+    /// 
+    fn on_conflict(&mut self, _conflict_clause: &Clause, _learned_clause: &Clause) {}
+
+    /// Chooses the literal to decide
+    /// 
+    /// # Arguments
+    /// 
+    /// * `model` - The current partial assignment
+    /// * `clauses` - The clauses of the formula
+    /// * `learned_clauses` - The learned clauses
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<isize>` - An unassigned literal, None if every variable is assigned
+    /// 
+    fn decide(&mut self, model: &Model, clauses: &Vec<Clause>, learned_clauses: &Vec<Clause>) -> Option<isize>;
+}
+
+/// Creates a decision heuristic from its name
+/// 
+/// # Arguments
+/// 
+/// * `name` - The name of the heuristic, ignoring the case
+/// 
+/// # Returns
+/// 
+/// * `Option<Box<dyn DecisionHeuristic>>` - The heuristic, None if the name is unknown
+/// 
+pub fn from_name(name: &str) -> Option<Box<dyn DecisionHeuristic>> {
+    match name.to_lowercase().as_str() {
+        "vsids" => Some(Box::new(vsids::Vsids::new())),
+        "vmtf" => Some(Box::new(vmtf::Vmtf::new())),
+        "chb" | "lrb" => Some(Box::new(chb::Chb::new())),
+        "jw" | "jeroslow-wang" => Some(Box::new(jeroslow_wang::JeroslowWang::new())),
+        "dlis" => Some(Box::new(dlis::Dlis::new())),
+        "moms" => Some(Box::new(moms::Moms::new())),
+        _ => None,
+    }
+}
+
+/// Returns the clauses not satisfied by a partial assignment
+/// 
+/// # Arguments
+/// 
+/// * `model` - The partial assignment
+/// * `clauses` - The clauses of the formula
+/// * `learned_clauses` - The learned clauses
+/// 
+/// # Returns
+/// 
+/// * `impl Iterator<Item = &Clause>` - The clauses without a true literal
+/// 
+pub fn unsatisfied_clauses<'a>(model: &'a Model, clauses: &'a Vec<Clause>, learned_clauses: &'a Vec<Clause>) -> impl Iterator<Item = &'a Clause> {
+    clauses.iter().chain(learned_clauses.iter())
+        .filter(move |clause| !clause.iter_literals().any(|&literal| model.has(literal)))
+}
+
+/// Returns the first unassigned variable, used when a heuristic has no better choice
+/// 
+/// # Arguments
+/// 
+/// * `model` - The partial assignment
+/// 
+/// # Returns
+/// 
+/// * `Option<isize>` - The negative literal of the variable, None if every variable is assigned
+/// 
+pub fn first_unassigned(model: &Model) -> Option<isize> {
+    (1..=model.len()).find(|&variable| !model.has_abs(variable)).map(|variable| -(variable as isize))
+}
\ No newline at end of file
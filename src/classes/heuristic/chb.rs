@@ -0,0 +1,80 @@
+use crate::consts::heuristics;
+use crate::classes::{clause::Clause, model::Model};
+use crate::classes::heuristic::{self, DecisionHeuristic};
+
+/// Conflict History Based branching
+/// Every variable has a Q score, updated when the variable is assigned with a reward that is higher if the variable took part
+/// in a recent conflict. The step size of the update decays with the conflicts. The unassigned variable with the highest score
+/// is decided with its saved phase.
+pub struct Chb {
+    scores: Vec<f64>,
+    last_conflicts: Vec<usize>,
+    phases: Vec<bool>,
+    conflicts: usize,
+    step: f64,
+    after_conflict: bool,
+}
+
+impl Chb {
+    pub fn new() -> Chb {
+        Chb {
+            scores: Vec::new(),
+            last_conflicts: Vec::new(),
+            phases: Vec::new(),
+            conflicts: 0,
+            step: heuristics::CHB_STEP_START,
+            after_conflict: false,
+        }
+    }
+}
+
+impl DecisionHeuristic for Chb {
+    fn name(&self) -> &'static str {
+        "CHB"
+    }
+
+    fn reset(&mut self) {
+        *self = Chb::new();
+    }
+
+    fn resize(&mut self, num_variables: usize) {
+        if self.scores.len() != num_variables {
+            self.scores = vec![0.0; num_variables];
+            self.last_conflicts = vec![0; num_variables];
+            self.phases = vec![false; num_variables];
+        }
+    }
+
+    fn on_assign(&mut self, literal: isize) {
+        let idx = literal.abs() as usize - 1;
+        if idx >= self.scores.len() {
+            return;
+        }
+        let multiplier = if self.after_conflict { 1.0 } else { 0.9 };
+        let reward = multiplier / (self.conflicts - self.last_conflicts[idx] + 1) as f64;
+        self.scores[idx] = (1.0 - self.step) * self.scores[idx] + self.step * reward;
+        self.phases[idx] = literal > 0;
+    }
+
+    fn on_conflict(&mut self, conflict_clause: &Clause, learned_clause: &Clause) {
+        self.conflicts += 1;
+        self.after_conflict = true;
+        for &literal in conflict_clause.iter_literals().chain(learned_clause.iter_literals()) {
+            if let Some(last_conflict) = self.last_conflicts.get_mut(literal.abs() as usize - 1) {
+                *last_conflict = self.conflicts;
+            }
+        }
+        self.step = (self.step - heuristics::CHB_STEP_DECAY).max(heuristics::CHB_STEP_MIN);
+    }
+
+    fn decide(&mut self, model: &Model, _clauses: &Vec<Clause>, _learned_clauses: &Vec<Clause>) -> Option<isize> {
+        self.after_conflict = false;
+        let best = self.scores.iter().enumerate()
+            .filter(|&(idx, _)| idx < model.len() && !model.has_abs(idx + 1))
+            .max_by(|a, b| a.1.total_cmp(b.1).then(b.0.cmp(&a.0)));
+        return match best {
+            Some((idx, _)) => Some(if self.phases[idx] { (idx + 1) as isize } else { -((idx + 1) as isize) }),
+            None => heuristic::first_unassigned(model),
+        };
+    }
+}
\ No newline at end of file
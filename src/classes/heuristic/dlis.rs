@@ -0,0 +1,54 @@
+use crate::classes::{clause::Clause, model::Model};
+use crate::classes::heuristic::{self, DecisionHeuristic};
+
+/// Dynamic Largest Individual Sum
+/// The unassigned literal with the most occurrences in the unsatisfied clauses is decided.
+pub struct Dlis {
+    occurrences: Vec<(usize, usize)>,
+}
+
+impl Dlis {
+    pub fn new() -> Dlis {
+        Dlis {
+            occurrences: Vec::new(),
+        }
+    }
+}
+
+impl DecisionHeuristic for Dlis {
+    fn name(&self) -> &'static str {
+        "DLIS"
+    }
+
+    fn reset(&mut self) {
+        self.occurrences = Vec::new();
+    }
+
+    fn resize(&mut self, num_variables: usize) {
+        self.occurrences = vec![(0, 0); num_variables];
+    }
+
+    fn decide(&mut self, model: &Model, clauses: &Vec<Clause>, learned_clauses: &Vec<Clause>) -> Option<isize> {
+        self.occurrences = vec![(0, 0); model.len()];
+        for clause in heuristic::unsatisfied_clauses(model, clauses, learned_clauses) {
+            for &literal in clause.iter_literals() {
+                let occurrences = &mut self.occurrences[literal.abs() as usize - 1];
+                if literal > 0 {
+                    occurrences.0 += 1;
+                } else {
+                    occurrences.1 += 1;
+                }
+            }
+        }
+
+        let best = self.occurrences.iter().enumerate()
+            .filter(|&(idx, _)| !model.has_abs(idx + 1))
+            .flat_map(|(idx, &(positive, negative))| [((idx + 1) as isize, positive), (-((idx + 1) as isize), negative)])
+            .filter(|&(_, count)| count > 0)
+            .max_by(|a, b| a.1.cmp(&b.1).then(b.0.abs().cmp(&a.0.abs())));
+        return match best {
+            Some((literal, _)) => Some(literal),
+            None => heuristic::first_unassigned(model),
+        };
+    }
+}
\ No newline at end of file
@@ -0,0 +1,54 @@
+use crate::classes::{clause::Clause, model::Model};
+use crate::classes::heuristic::{self, DecisionHeuristic};
+
+/// Two-sided Jeroslow-Wang
+/// Every literal of an unsatisfied clause of length n scores 2^-n, the variable with the highest sum of the scores of its two
+/// literals is decided with the polarity of the higher score.
+pub struct JeroslowWang {
+    scores: Vec<(f64, f64)>,
+}
+
+impl JeroslowWang {
+    pub fn new() -> JeroslowWang {
+        JeroslowWang {
+            scores: Vec::new(),
+        }
+    }
+}
+
+impl DecisionHeuristic for JeroslowWang {
+    fn name(&self) -> &'static str {
+        "JW"
+    }
+
+    fn reset(&mut self) {
+        self.scores = Vec::new();
+    }
+
+    fn resize(&mut self, num_variables: usize) {
+        self.scores = vec![(0.0, 0.0); num_variables];
+    }
+
+    fn decide(&mut self, model: &Model, clauses: &Vec<Clause>, learned_clauses: &Vec<Clause>) -> Option<isize> {
+        self.scores = vec![(0.0, 0.0); model.len()];
+        for clause in heuristic::unsatisfied_clauses(model, clauses, learned_clauses) {
+            let weight = 2f64.powi(-(clause.literals_len() as i32));
+            for &literal in clause.iter_literals() {
+                let score = &mut self.scores[literal.abs() as usize - 1];
+                if literal > 0 {
+                    score.0 += weight;
+                } else {
+                    score.1 += weight;
+                }
+            }
+        }
+
+        let best = self.scores.iter().enumerate()
+            .filter(|&(idx, score)| score.0 + score.1 > 0.0 && !model.has_abs(idx + 1))
+            .max_by(|a, b| (a.1.0 + a.1.1).total_cmp(&(b.1.0 + b.1.1)).then(b.0.cmp(&a.0)));
+        return match best {
+            Some((idx, score)) => Some(if score.0 >= score.1 { (idx + 1) as isize } else { -((idx + 1) as isize) }),
+            None => heuristic::first_unassigned(model),
+        };
+    }
+}
\ No newline at end of file
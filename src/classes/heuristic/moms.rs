@@ -0,0 +1,64 @@
+use crate::consts::heuristics;
+use crate::classes::{clause::Clause, model::Model};
+use crate::classes::heuristic::{self, DecisionHeuristic};
+
+/// Maximum Occurrences in clauses of Minimum Size
+/// Only the unsatisfied clauses with the fewest unassigned literals are counted. The variable with the highest
+/// (f(x) + f(-x)) * k + f(x) * f(-x) is decided with its most frequent polarity, where f counts the occurrences of a literal.
+pub struct Moms {
+    occurrences: Vec<(usize, usize)>,
+}
+
+impl Moms {
+    pub fn new() -> Moms {
+        Moms {
+            occurrences: Vec::new(),
+        }
+    }
+}
+
+impl DecisionHeuristic for Moms {
+    fn name(&self) -> &'static str {
+        "MOMs"
+    }
+
+    fn reset(&mut self) {
+        self.occurrences = Vec::new();
+    }
+
+    fn resize(&mut self, num_variables: usize) {
+        self.occurrences = vec![(0, 0); num_variables];
+    }
+
+    fn decide(&mut self, model: &Model, clauses: &Vec<Clause>, learned_clauses: &Vec<Clause>) -> Option<isize> {
+        let unassigned = |clause: &Clause| -> Vec<isize> {
+            clause.iter_literals().filter(|&&literal| !model.has_abs(literal.abs() as usize)).cloned().collect()
+        };
+        let clauses: Vec<Vec<isize>> = heuristic::unsatisfied_clauses(model, clauses, learned_clauses)
+            .map(unassigned)
+            .filter(|literals| !literals.is_empty())
+            .collect();
+        let minimum_size = clauses.iter().map(|literals| literals.len()).min();
+
+        self.occurrences = vec![(0, 0); model.len()];
+        for literals in clauses.iter().filter(|literals| Some(literals.len()) == minimum_size) {
+            for &literal in literals.iter() {
+                let occurrences = &mut self.occurrences[literal.abs() as usize - 1];
+                if literal > 0 {
+                    occurrences.0 += 1;
+                } else {
+                    occurrences.1 += 1;
+                }
+            }
+        }
+
+        let score = |&(positive, negative): &(usize, usize)| (positive + negative) * heuristics::MOMS_WEIGHT + positive * negative;
+        let best = self.occurrences.iter().enumerate()
+            .filter(|&(_, occurrences)| occurrences.0 + occurrences.1 > 0)
+            .max_by(|a, b| score(a.1).cmp(&score(b.1)).then(b.0.cmp(&a.0)));
+        return match best {
+            Some((idx, &(positive, negative))) => Some(if positive >= negative { (idx + 1) as isize } else { -((idx + 1) as isize) }),
+            None => heuristic::first_unassigned(model),
+        };
+    }
+}
\ No newline at end of file
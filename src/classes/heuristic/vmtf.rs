@@ -0,0 +1,67 @@
+use crate::classes::{clause::Clause, model::Model};
+use crate::classes::heuristic::{self, DecisionHeuristic};
+
+/// Variable Move To Front
+/// The variables are kept in a queue ordered by the time they were last bumped, the variables of every learned clause are
+/// moved to the front. The unassigned variable nearest to the front is decided with its saved phase.
+pub struct Vmtf {
+    timestamps: Vec<usize>,
+    phases: Vec<bool>,
+    time: usize,
+}
+
+impl Vmtf {
+    pub fn new() -> Vmtf {
+        Vmtf {
+            timestamps: Vec::new(),
+            phases: Vec::new(),
+            time: 0,
+        }
+    }
+}
+
+impl DecisionHeuristic for Vmtf {
+    fn name(&self) -> &'static str {
+        "VMTF"
+    }
+
+    fn reset(&mut self) {
+        self.timestamps = Vec::new();
+        self.phases = Vec::new();
+        self.time = 0;
+    }
+
+    fn resize(&mut self, num_variables: usize) {
+        if self.timestamps.len() != num_variables {
+            // The initial queue follows the order of the variables, the first variable is at the front.
+            self.timestamps = (0..num_variables).map(|idx| num_variables - idx).collect();
+            self.phases = vec![false; num_variables];
+            self.time = num_variables;
+        }
+    }
+
+    fn on_assign(&mut self, literal: isize) {
+        if let Some(phase) = self.phases.get_mut(literal.abs() as usize - 1) {
+            *phase = literal > 0;
+        }
+    }
+
+    fn on_conflict(&mut self, _conflict_clause: &Clause, learned_clause: &Clause) {
+        for &literal in learned_clause.iter_literals() {
+            if let Some(timestamp) = self.timestamps.get_mut(literal.abs() as usize - 1) {
+                self.time += 1;
+                *timestamp = self.time;
+            }
+        }
+    }
+
+    fn decide(&mut self, model: &Model, _clauses: &Vec<Clause>, _learned_clauses: &Vec<Clause>) -> Option<isize> {
+        let front = self.timestamps.iter().enumerate()
+            .filter(|&(idx, _)| idx < model.len() && !model.has_abs(idx + 1))
+            .max_by_key(|&(_, timestamp)| timestamp);
+        return match front {
+            Some((idx, _)) => Some(if self.phases[idx] { (idx + 1) as isize } else { -((idx + 1) as isize) }),
+            None => heuristic::first_unassigned(model),
+        };
+    }
+}
\ No newline at end of file
@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use crate::classes::{clause::Clause, model::Model};
+use crate::classes::heuristic::{self, DecisionHeuristic};
+
+/// Variable State Independent Decaying Sum
+/// Every literal has a score, the scores are halved at every conflict and the literals of the conflict clause are increased.
+/// The unassigned variable with the highest score is decided with its best polarity. When no unassigned variable has a score,
+/// the literal watched by the most unsatisfied clauses is decided.
+pub struct Vsids {
+    scores: Vec<(f32, f32)>,
+}
+
+impl Vsids {
+    pub fn new() -> Vsids {
+        Vsids {
+            scores: Vec::new(),
+        }
+    }
+}
+
+impl DecisionHeuristic for Vsids {
+    fn name(&self) -> &'static str {
+        "VSIDS"
+    }
+
+    fn reset(&mut self) {
+        self.scores = Vec::new();
+    }
+
+    fn resize(&mut self, num_variables: usize) {
+        if self.scores.len() != num_variables {
+            self.scores = vec![(0.0, 0.0); num_variables];
+        }
+    }
+
+    fn on_conflict(&mut self, conflict_clause: &Clause, _learned_clause: &Clause) {
+        for score in self.scores.iter_mut() {
+            *score = (score.0 / 2.0, score.1 / 2.0);
+            if score.0 + score.1 < f32::EPSILON {
+                *score = (0.0, 0.0);
+            }
+        }
+        for &literal in conflict_clause.iter_literals() {
+            if let Some(score) = self.scores.get_mut(literal.abs() as usize - 1) {
+                if literal > 0 {
+                    score.0 += 1.0;
+                } else {
+                    score.1 += 1.0;
+                }
+            }
+        }
+    }
+
+    fn decide(&mut self, model: &Model, clauses: &Vec<Clause>, learned_clauses: &Vec<Clause>) -> Option<isize> {
+        let mut scores: Vec<(usize, (f32, f32))> = self.scores.iter().cloned().enumerate()
+            .filter(|&(idx, score)| score.0 + score.1 > 0.0 && idx < model.len() && !model.has_abs(idx + 1))
+            .collect();
+        scores.sort_by(|a, b| (b.1.0 + b.1.1).total_cmp(&(a.1.0 + a.1.1)));
+
+        if let Some(&(idx, score)) = scores.first() {
+            let sign = if score.0 < score.1 { -1 } else { 1 };
+            return Some((idx + 1) as isize * sign);
+        }
+
+        // Count the unassigned watched literals of the unsatisfied clauses, by variable.
+        let mut counts: HashMap<isize, (usize, usize)> = HashMap::new();
+        for clause in heuristic::unsatisfied_clauses(model, clauses, learned_clauses).filter(|clause| clause.literals_len() > 0) {
+            let (first, second) = clause.get_watched_literals();
+            let watched = if first == second { vec![first] } else { vec![first, second] };
+            for literal in watched {
+                if !model.has_abs(literal.abs() as usize) {
+                    let count = counts.entry(literal.abs()).or_insert((0, 0));
+                    if literal > 0 {
+                        count.0 += 1;
+                    } else {
+                        count.1 += 1;
+                    }
+                }
+            }
+        }
+
+        let best = counts.iter()
+            .max_by(|a, b| (a.1.0 + a.1.1).cmp(&(b.1.0 + b.1.1)).then(b.0.cmp(a.0)));
+        return match best {
+            Some((&variable, &(positive, negative))) => Some(if positive < negative { -variable } else { variable }),
+            None => heuristic::first_unassigned(model),
+        };
+    }
+}
\ No newline at end of file
@@ -0,0 +1,188 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::interrupt;
+use crate::classes::stats::Stats;
+
+pub struct Limits {
+    timeout: Option<Duration>,
+    max_conflicts: Option<usize>,
+    max_decisions: Option<usize>,
+    max_memory: Option<usize>,
+    stop_flag: Option<Arc<AtomicBool>>,
+
+    start_time: Option<Instant>,
+}
+
+impl Limits {
+    pub fn new() -> Limits {
+        Limits {
+            timeout: None,
+            max_conflicts: None,
+            max_decisions: None,
+            max_memory: None,
+            stop_flag: None,
+
+            start_time: None,
+        }
+    }
+
+    /// Starts the clock of the timeout, if it is not already running.
+    /// The clock keeps running across incremental calls until it is reset.
+    pub fn start(&mut self) {
+        if self.start_time.is_none() {
+            self.start_time = Some(Instant::now());
+        }
+    }
+
+    /// Stops the clock of the timeout.
+    pub fn reset(&mut self) {
+        self.start_time = None;
+    }
+
+    /// Returns the wall-clock timeout
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<Duration>` - The timeout, None if there is no timeout
+    /// 
+    pub fn get_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Sets the wall-clock timeout
+    /// 
+    /// # Arguments
+    /// 
+    /// * `timeout` - The timeout, None to remove it
+    /// 
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    /// Returns the time left before the timeout
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<Duration>` - The time left, the whole timeout if the clock is not running, None if there is no timeout
+    /// 
+    pub fn get_remaining_timeout(&self) -> Option<Duration> {
+        match self.start_time {
+            Some(start_time) => self.timeout.map(|timeout| timeout.saturating_sub(start_time.elapsed())),
+            None => self.timeout,
+        }
+    }
+
+    /// Returns the maximum number of conflicts
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<usize>` - The maximum number of conflicts, None if there is no limit
+    /// 
+    pub fn get_max_conflicts(&self) -> Option<usize> {
+        self.max_conflicts
+    }
+
+    /// Sets the maximum number of conflicts
+    /// 
+    /// # Arguments
+    /// 
+    /// * `max_conflicts` - The maximum number of conflicts, None to remove the limit
+    /// 
+    pub fn set_max_conflicts(&mut self, max_conflicts: Option<usize>) {
+        self.max_conflicts = max_conflicts;
+    }
+
+    /// Returns the maximum number of decisions
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<usize>` - The maximum number of decisions, None if there is no limit
+    /// 
+    pub fn get_max_decisions(&self) -> Option<usize> {
+        self.max_decisions
+    }
+
+    /// Sets the maximum number of decisions
+    /// 
+    /// # Arguments
+    /// 
+    /// * `max_decisions` - The maximum number of decisions, None to remove the limit
+    /// 
+    pub fn set_max_decisions(&mut self, max_decisions: Option<usize>) {
+        self.max_decisions = max_decisions;
+    }
+
+    /// Returns the maximum physical memory in megabytes
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<usize>` - The maximum physical memory, None if there is no limit
+    /// 
+    pub fn get_max_memory(&self) -> Option<usize> {
+        self.max_memory
+    }
+
+    /// Sets the maximum physical memory in megabytes
+    /// 
+    /// # Arguments
+    /// 
+    /// * `max_memory` - The maximum physical memory, None to remove the limit
+    /// 
+    pub fn set_max_memory(&mut self, max_memory: Option<usize>) {
+        self.max_memory = max_memory;
+    }
+
+    /// Sets a flag shared with other solvers, the search stops when it is raised
+    /// 
+    /// # Arguments
+    /// 
+    /// * `stop_flag` - The flag, None to remove it
+    /// 
+    pub fn set_stop_flag(&mut self, stop_flag: Option<Arc<AtomicBool>>) {
+        self.stop_flag = stop_flag;
+    }
+
+    /// Checks if one of the limits is reached or the search was interrupted by the user
+    /// 
+    /// # Arguments
+    /// 
+    /// * `stats` - The statistics of the solver, the memory usage is the one sampled by the last update
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<&str>` - The reason to stop the search, None if the search can go on
+    /// 
+    pub fn check(&self, stats: &Stats) -> Option<&'static str> {
+        if interrupt::is_interrupted() {
+            return Some("interrupted by the user");
+        }
+        if let Some(stop_flag) = &self.stop_flag {
+            if stop_flag.load(Ordering::SeqCst) {
+                return Some("stopped by another worker");
+            }
+        }
+        if let (Some(timeout), Some(start_time)) = (self.timeout, self.start_time) {
+            if start_time.elapsed() >= timeout {
+                return Some("timeout reached");
+            }
+        }
+        if let Some(max_conflicts) = self.max_conflicts {
+            if stats.get_conflicts() >= max_conflicts {
+                return Some("conflict limit reached");
+            }
+        }
+        if let Some(max_decisions) = self.max_decisions {
+            if stats.get_decisions() >= max_decisions {
+                return Some("decision limit reached");
+            }
+        }
+        if let Some(max_memory) = self.max_memory {
+            if stats.get_physical_memory_bytes() >= max_memory * 1024 * 1024 {
+                return Some("memory limit reached");
+            }
+        }
+        return None;
+    }
+}
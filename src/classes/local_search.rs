@@ -0,0 +1,394 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::consts::local_search;
+use crate::classes::{formula::Formula, limits::Limits, model::Model, stats::Stats};
+
+pub struct LocalSearch {
+    noise: f64,
+    cb: f64,
+    max_flips: usize,
+    max_restarts: usize,
+    seed: u64,
+}
+
+/// The assignment of a try of the local search, with the number of true literals of each clause.
+struct State {
+    clauses: Vec<Vec<isize>>,
+    occurrences: Vec<Vec<usize>>,
+    assignment: Vec<bool>,
+    true_literals: Vec<usize>,
+    falsified: Vec<usize>,
+    falsified_position: Vec<Option<usize>>,
+}
+
+impl LocalSearch {
+    pub fn new() -> LocalSearch {
+        LocalSearch {
+            noise: local_search::DEFAULT_NOISE,
+            cb: local_search::DEFAULT_CB,
+            max_flips: local_search::DEFAULT_MAX_FLIPS,
+            max_restarts: local_search::DEFAULT_MAX_RESTARTS,
+            seed: 0,
+        }
+    }
+
+    /// Returns the probability of a random walk step of WalkSAT.
+    /// 
+    /// # Returns
+    /// 
+    /// * `f64` - The noise, between 0 and 1.
+    /// 
+    pub fn get_noise(&self) -> f64 {
+        self.noise
+    }
+
+    /// Set the probability of a random walk step of WalkSAT.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `noise` - The noise, it is clamped between 0 and 1.
+    /// 
+    pub fn set_noise(&mut self, noise: f64) {
+        self.noise = noise.clamp(0.0, 1.0);
+    }
+
+    /// Returns the base of the break values of ProbSAT.
+    /// 
+    /// # Returns
+    /// 
+    /// * `f64` - The cb parameter.
+    /// 
+    pub fn get_cb(&self) -> f64 {
+        self.cb
+    }
+
+    /// Set the base of the break values of ProbSAT, a variable is flipped with probability proportional to (eps + break)^-cb.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `cb` - The cb parameter, it must be positive.
+    /// 
+    pub fn set_cb(&mut self, cb: f64) {
+        self.cb = cb.max(0.0);
+    }
+
+    /// Returns the maximum number of flips of a try.
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The flip limit.
+    /// 
+    pub fn get_max_flips(&self) -> usize {
+        self.max_flips
+    }
+
+    /// Set the maximum number of flips of a try, then the search restarts from a new random assignment.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `max_flips` - The flip limit.
+    /// 
+    pub fn set_max_flips(&mut self, max_flips: usize) {
+        self.max_flips = max_flips.max(1);
+    }
+
+    /// Returns the maximum number of restarts.
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The restart limit.
+    /// 
+    pub fn get_max_restarts(&self) -> usize {
+        self.max_restarts
+    }
+
+    /// Set the maximum number of restarts, the search gives up after the last try.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `max_restarts` - The restart limit.
+    /// 
+    pub fn set_max_restarts(&mut self, max_restarts: usize) {
+        self.max_restarts = max_restarts;
+    }
+
+    /// Returns the seed of the random generator.
+    /// 
+    /// # Returns
+    /// 
+    /// * `u64` - The seed.
+    /// 
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Set the seed of the random generator, the same seed gives the same search.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `seed` - The seed.
+    /// 
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    /// Search a model with WalkSAT.
+    /// A falsified clause is picked at random, a variable that does not falsify other clauses is flipped if there is one,
+    /// otherwise a random variable of the clause with probability equal to the noise, or the one that falsifies the fewest clauses.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `formula` - The formula.
+    /// * `limits` - The limits of the solver.
+    /// * `stats` - The statistics of the solver.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<Model>` - The model, None if it is not found within the limits.
+    /// 
+    pub fn walksat(&self, formula: &Formula, limits: &Limits, stats: &mut Stats) -> Option<Model> {
+        let noise = self.noise;
+        return self.search(formula, limits, stats, |state, clause_idx, rng| {
+            let breaks: Vec<(usize, usize)> = state.clauses[clause_idx].iter()
+                .map(|&literal| (literal.abs() as usize, state.break_value(literal.abs() as usize)))
+                .collect();
+            if let Some(&(variable, _)) = breaks.iter().find(|&&(_, value)| value == 0) {
+                return variable;
+            }
+            if rng.gen_bool(noise) {
+                return breaks[rng.gen_range(0..breaks.len())].0;
+            }
+            return breaks.iter().min_by_key(|&&(_, value)| value).unwrap().0;
+        });
+    }
+
+    /// Search a model with ProbSAT.
+    /// A falsified clause is picked at random, and one of its variables is flipped with probability proportional to (eps + break)^-cb,
+    /// where break is the number of clauses falsified by the flip.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `formula` - The formula.
+    /// * `limits` - The limits of the solver.
+    /// * `stats` - The statistics of the solver.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<Model>` - The model, None if it is not found within the limits.
+    /// 
+    pub fn probsat(&self, formula: &Formula, limits: &Limits, stats: &mut Stats) -> Option<Model> {
+        let cb = self.cb;
+        return self.search(formula, limits, stats, |state, clause_idx, rng| {
+            let scores: Vec<(usize, f64)> = state.clauses[clause_idx].iter()
+                .map(|&literal| literal.abs() as usize)
+                .map(|variable| (variable, (local_search::PROBSAT_EPS + state.break_value(variable) as f64).powf(-cb)))
+                .collect();
+            let mut threshold = rng.gen::<f64>() * scores.iter().map(|&(_, score)| score).sum::<f64>();
+            for &(variable, score) in scores.iter() {
+                if threshold < score {
+                    return variable;
+                }
+                threshold -= score;
+            }
+            return scores[scores.len() - 1].0;
+        });
+    }
+
+    /// Main loop of the local search, the tries start from random assignments.
+    /// A formula with an empty clause has no model, and no variable can be flipped in the clause, so the search gives up at once.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `formula` - The formula.
+    /// * `limits` - The limits of the solver.
+    /// * `stats` - The statistics of the solver.
+    /// * `pick` - Chooses the variable to flip in a falsified clause.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<Model>` - The model, None if it is not found within the limits or if the formula has an empty clause.
+    /// 
+    fn search<F>(&self, formula: &Formula, limits: &Limits, stats: &mut Stats, mut pick: F) -> Option<Model>
+        where F: FnMut(&State, usize, &mut StdRng) -> usize {
+
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut state = State::new(formula);
+        if state.clauses.iter().any(|clause| clause.is_empty()) {
+            return None;
+        }
+
+        for restart in 0..=self.max_restarts {
+            if restart > 0 {
+                stats.increase_restarts();
+            }
+            let assignment: Vec<bool> = (0..=formula.get_num_variables()).map(|_| rng.gen_bool(0.5)).collect();
+            state.reset(assignment);
+
+            for flip in 0..self.max_flips {
+                if state.falsified.is_empty() {
+                    return Some(state.to_model());
+                }
+                if flip % local_search::LIMIT_CHECK_INTERVAL == 0 {
+                    stats.update();
+                    if let Some(limit) = limits.check(stats) {
+                        stats.set_limit_reached(limit);
+                        return None;
+                    }
+                }
+                let clause_idx = state.falsified[rng.gen_range(0..state.falsified.len())];
+                let variable = pick(&state, clause_idx, &mut rng);
+                state.flip(variable);
+                stats.increase_flips();
+            }
+            if state.falsified.is_empty() {
+                return Some(state.to_model());
+            }
+        }
+
+        return None;
+    }
+}
+
+impl State {
+    /// Creates the state of a formula, the tautologies are ignored.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `formula` - The formula.
+    /// 
+    /// # Returns
+    /// 
+    /// * `State` - The state, without an assignment.
+    /// 
+    fn new(formula: &Formula) -> State {
+        let clauses: Vec<Vec<isize>> = formula.get_clauses().iter()
+            .map(|clause| clause.iter_literals().cloned().collect::<Vec<isize>>())
+            .filter(|literals| !literals.iter().any(|literal| literals.contains(&-literal)))
+            .collect();
+        let mut occurrences: Vec<Vec<usize>> = vec![Vec::new(); 2 * formula.get_num_variables() + 2];
+        for (clause_idx, clause) in clauses.iter().enumerate() {
+            for &literal in clause.iter() {
+                occurrences[State::literal_index(literal)].push(clause_idx);
+            }
+        }
+        State {
+            true_literals: vec![0; clauses.len()],
+            falsified_position: vec![None; clauses.len()],
+            clauses,
+            occurrences,
+            assignment: Vec::new(),
+            falsified: Vec::new(),
+        }
+    }
+
+    /// Returns the index of a literal in the occurrence lists.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literal` - The literal.
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The index.
+    /// 
+    fn literal_index(literal: isize) -> usize {
+        return 2 * literal.abs() as usize + (literal < 0) as usize;
+    }
+
+    /// Check if a literal is true in the assignment.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literal` - The literal.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the literal is true.
+    /// 
+    fn is_true(&self, literal: isize) -> bool {
+        return self.assignment[literal.abs() as usize] == (literal > 0);
+    }
+
+    /// Start a try from an assignment.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `assignment` - The value of each variable, indexed by variable.
+    /// 
+    fn reset(&mut self, assignment: Vec<bool>) {
+        self.assignment = assignment;
+        self.falsified.clear();
+        for clause_idx in 0..self.clauses.len() {
+            self.true_literals[clause_idx] = self.clauses[clause_idx].iter().filter(|&&literal| self.is_true(literal)).count();
+            self.falsified_position[clause_idx] = None;
+            if self.true_literals[clause_idx] == 0 {
+                self.falsified_position[clause_idx] = Some(self.falsified.len());
+                self.falsified.push(clause_idx);
+            }
+        }
+    }
+
+    /// Returns the number of clauses falsified by flipping a variable.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `variable` - The variable.
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The break value.
+    /// 
+    fn break_value(&self, variable: usize) -> usize {
+        let true_literal = if self.assignment[variable] { variable as isize } else { -(variable as isize) };
+        return self.occurrences[State::literal_index(true_literal)].iter()
+            .filter(|&&clause_idx| self.true_literals[clause_idx] == 1)
+            .count();
+    }
+
+    /// Flip a variable and update the falsified clauses.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `variable` - The variable.
+    /// 
+    fn flip(&mut self, variable: usize) {
+        let old_literal = if self.assignment[variable] { variable as isize } else { -(variable as isize) };
+        self.assignment[variable] = !self.assignment[variable];
+
+        for occurrence_idx in 0..self.occurrences[State::literal_index(-old_literal)].len() {
+            let clause_idx = self.occurrences[State::literal_index(-old_literal)][occurrence_idx];
+            self.true_literals[clause_idx] += 1;
+            if let (1, Some(position)) = (self.true_literals[clause_idx], self.falsified_position[clause_idx]) {
+                let last = self.falsified.pop().unwrap();
+                if last != clause_idx {
+                    self.falsified[position] = last;
+                    self.falsified_position[last] = Some(position);
+                }
+                self.falsified_position[clause_idx] = None;
+            }
+        }
+        for occurrence_idx in 0..self.occurrences[State::literal_index(old_literal)].len() {
+            let clause_idx = self.occurrences[State::literal_index(old_literal)][occurrence_idx];
+            self.true_literals[clause_idx] -= 1;
+            if self.true_literals[clause_idx] == 0 {
+                self.falsified_position[clause_idx] = Some(self.falsified.len());
+                self.falsified.push(clause_idx);
+            }
+        }
+    }
+
+    /// Returns the assignment as a model.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Model` - The model, every variable is assigned.
+    /// 
+    fn to_model(&self) -> Model {
+        let mut model = Model::new(Some(self.assignment.len() - 1));
+        for variable in 1..self.assignment.len() {
+            model.add(if self.assignment[variable] { variable as isize } else { -(variable as isize) });
+        }
+        return model;
+    }
+
+}
\ No newline at end of file
@@ -7,6 +7,7 @@ enum ModelValue {
     Unknown
 }
 
+#[derive(Clone)]
 pub struct Model {
     model: Vec<ModelValue>,
 }
@@ -29,6 +30,16 @@ impl Model {
         self.model.resize(size, ModelValue::Unknown);
     }
 
+    /// Returns the number of variables of the model
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of variables
+    /// 
+    pub fn len(&self) -> usize {
+        self.model.len()
+    }
+
     /// Adds a literal to the model
     /// 
     /// # Arguments
@@ -108,9 +119,35 @@ impl Model {
         }
     }
 
+    /// Returns the literals assigned by the model
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<isize>` - The assigned literals, ordered by variable
+    /// 
+    pub fn get_literals(&self) -> Vec<isize> {
+        let mut literals: Vec<isize> = Vec::new();
+        for (idx, value) in self.model.iter().enumerate() {
+            match value {
+                ModelValue::Positive => literals.push(idx as isize + 1),
+                ModelValue::Negative => literals.push(-(idx as isize + 1)),
+                ModelValue::Unknown => (),
+            }
+        }
+        return literals;
+    }
+
     /// Prints the model
-    pub fn print(&self) {
+    /// 
+    /// # Arguments
+    /// 
+    /// * `is_hidden` - True for the variables that are not printed
+    /// 
+    pub fn print(&self, is_hidden: impl Fn(usize) -> bool) {
         for (idx, value) in self.model.iter().enumerate() {
+            if is_hidden(idx + 1) {
+                continue;
+            }
             match value {
                 ModelValue::Positive => print!("{} ", idx + 1),
                 ModelValue::Negative => print!("-{} ", idx + 1),
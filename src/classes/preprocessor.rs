@@ -0,0 +1,1220 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::consts::{sat::SAT, preprocessing};
+use crate::classes::{clause::Clause, formula::Formula, model::Model};
+
+pub struct Preprocessor {
+    clauses: Vec<Vec<isize>>,
+    clause_ids: Vec<usize>,
+    is_learned: Vec<bool>,
+    removed: Vec<bool>,
+    occurrences: HashMap<isize, Vec<usize>>,
+    current_clause_id: usize,
+
+    assignment: Model,
+    units: Vec<(isize, Option<usize>)>,
+    unit_clause_ids: HashMap<usize, usize>,
+    eliminated_variables: HashSet<usize>,
+    is_unsatisfiable: bool,
+
+    reconstruction_stack: Vec<(isize, Vec<isize>)>,
+    derivations: Vec<(Clause, Clause, Clause)>,
+
+    variable_elimination: bool,
+    probing: bool,
+    blocked_clause_elimination: bool,
+    covered_clause_elimination: bool,
+
+    clauses_eliminated: usize,
+    variables_eliminated: usize,
+}
+
+impl Preprocessor {
+    pub fn new() -> Preprocessor {
+        Preprocessor {
+            clauses: Vec::new(),
+            clause_ids: Vec::new(),
+            is_learned: Vec::new(),
+            removed: Vec::new(),
+            occurrences: HashMap::new(),
+            current_clause_id: 0,
+
+            assignment: Model::new(None),
+            units: Vec::new(),
+            unit_clause_ids: HashMap::new(),
+            eliminated_variables: HashSet::new(),
+            is_unsatisfiable: false,
+
+            reconstruction_stack: Vec::new(),
+            derivations: Vec::new(),
+
+            variable_elimination: false,
+            probing: false,
+            blocked_clause_elimination: false,
+            covered_clause_elimination: false,
+
+            clauses_eliminated: 0,
+            variables_eliminated: 0,
+        }
+    }
+
+    /// Check if the bounded variable elimination is enabled.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the bounded variable elimination is enabled, false otherwise.
+    /// 
+    pub fn is_variable_elimination_enabled(&self) -> bool {
+        self.variable_elimination
+    }
+
+    /// Set the bounded variable elimination to be enabled or disabled.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `enable` - The value to set the bounded variable elimination to.
+    /// 
+    pub fn set_variable_elimination_enabled(&mut self, enable: bool) {
+        self.variable_elimination = enable;
+    }
+
+    /// Check if the failed literal probing and the equivalent literal substitution are enabled.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the probing is enabled, false otherwise.
+    /// 
+    pub fn is_probing_enabled(&self) -> bool {
+        self.probing
+    }
+
+    /// Set the failed literal probing and the equivalent literal substitution to be enabled or disabled.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `enable` - The value to set the probing to.
+    /// 
+    pub fn set_probing_enabled(&mut self, enable: bool) {
+        self.probing = enable;
+    }
+
+    /// Check if the blocked clause elimination is enabled.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the blocked clause elimination is enabled, false otherwise.
+    /// 
+    pub fn is_blocked_clause_elimination_enabled(&self) -> bool {
+        self.blocked_clause_elimination
+    }
+
+    /// Set the blocked clause elimination to be enabled or disabled.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `enable` - The value to set the blocked clause elimination to.
+    /// 
+    pub fn set_blocked_clause_elimination_enabled(&mut self, enable: bool) {
+        self.blocked_clause_elimination = enable;
+    }
+
+    /// Check if the covered clause elimination is enabled.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the covered clause elimination is enabled, false otherwise.
+    /// 
+    pub fn is_covered_clause_elimination_enabled(&self) -> bool {
+        self.covered_clause_elimination
+    }
+
+    /// Set the covered clause elimination to be enabled or disabled.
+    /// It extends the blocked clause elimination, so it is applied only if the latter is enabled.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `enable` - The value to set the covered clause elimination to.
+    /// 
+    pub fn set_covered_clause_elimination_enabled(&mut self, enable: bool) {
+        self.covered_clause_elimination = enable;
+    }
+
+    /// Simplifies the formula.
+    /// It removes tautologies and duplicate clauses, then it applies top-level unit propagation, pure literal elimination,
+    /// subsumption, self-subsuming resolution and, if enabled, failed literal probing, equivalent literal substitution,
+    /// bounded variable elimination and blocked (or covered) clause elimination until nothing changes.
+    /// The clauses of the formula are replaced by the simplified ones, the number of variables is kept.
+    /// Every clause derived by resolution gets a new id, and the resolution step is kept as a derivation for the proof files.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `formula` - The formula to simplify
+    /// 
+    /// # Returns
+    /// 
+    /// * `SAT` - Unsatisfiable if the empty clause is derived, Satisfiable if every clause is eliminated, Unknown otherwise
+    /// 
+    pub fn run(&mut self, formula: &mut Formula) -> SAT {
+
+        self.reconstruction_stack = Vec::new();
+        self.eliminated_variables = HashSet::new();
+        self.load(formula, &Vec::new());
+        let clauses_before = formula.get_num_clauses();
+        let variables_before = self.count_variables();
+
+        loop {
+            if !self.propagate_units() {
+                break;
+            }
+            let mut changed = self.eliminate_pure_literals();
+            changed |= self.subsume();
+            if self.units.is_empty() {
+                changed |= self.probe();
+            }
+            if self.units.is_empty() && !self.is_unsatisfiable {
+                changed |= self.substitute_equivalent_literals();
+            }
+            if self.units.is_empty() && !self.is_unsatisfiable {
+                changed |= self.eliminate_variables();
+            }
+            if self.units.is_empty() && !self.is_unsatisfiable {
+                changed |= self.eliminate_blocked_clauses();
+            }
+            if !changed && self.units.is_empty() {
+                break;
+            }
+        }
+
+        return self.store(formula, None, clauses_before, variables_before);
+    }
+
+    /// Simplifies the formula and the learned clauses during the search, at decision level 0.
+    /// It applies top-level unit propagation, failed literal probing and equivalent literal substitution until nothing changes.
+    /// The reconstruction stack of the previous calls is kept, so the model can still be extended to the original formula.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `formula` - The formula to simplify
+    /// * `learned_clauses` - The learned clauses to simplify
+    /// 
+    /// # Returns
+    /// 
+    /// * `SAT` - Unsatisfiable if the empty clause is derived, Satisfiable if every clause is eliminated, Unknown otherwise
+    /// 
+    pub fn inprocess(&mut self, formula: &mut Formula, learned_clauses: &mut Vec<Clause>) -> SAT {
+
+        self.load(formula, learned_clauses);
+        let clauses_before = formula.get_num_clauses() + learned_clauses.len();
+        let variables_before = self.count_variables();
+
+        loop {
+            if !self.propagate_units() {
+                break;
+            }
+            let mut changed = self.probe();
+            if self.units.is_empty() && !self.is_unsatisfiable {
+                changed |= self.substitute_equivalent_literals();
+            }
+            if !changed && self.units.is_empty() {
+                break;
+            }
+        }
+
+        return self.store(formula, Some(learned_clauses), clauses_before, variables_before);
+    }
+
+    /// Replaces the clauses of the formula and the learned clauses with the working clauses
+    /// 
+    /// # Arguments
+    /// 
+    /// * `formula` - The formula
+    /// * `learned_clauses` - The learned clauses, None if they are not simplified
+    /// * `clauses_before` - The number of clauses before the simplification
+    /// * `variables_before` - The number of variables before the simplification
+    /// 
+    /// # Returns
+    /// 
+    /// * `SAT` - Unsatisfiable if the empty clause is derived, Satisfiable if every clause is eliminated, Unknown otherwise
+    /// 
+    fn store(&mut self, formula: &mut Formula, learned_clauses: Option<&mut Vec<Clause>>, clauses_before: usize, variables_before: usize) -> SAT {
+
+        if self.is_unsatisfiable {
+            self.clauses_eliminated = clauses_before;
+            self.variables_eliminated = variables_before;
+            return SAT::Unsatisfiable;
+        }
+
+        let mut clauses: Vec<Clause> = Vec::new();
+        let mut learned: Vec<Clause> = Vec::new();
+        for idx in 0..self.clauses.len() {
+            if !self.removed[idx] {
+                let mut clause = Clause::new();
+                clause.load_vec(self.clauses[idx].clone());
+                clause.set_id(self.clause_ids[idx]);
+                if self.is_learned[idx] {
+                    learned.push(clause);
+                } else {
+                    clauses.push(clause);
+                }
+            }
+        }
+
+        self.clauses_eliminated = clauses_before - clauses.len() - learned.len();
+        self.variables_eliminated = variables_before - self.count_variables();
+
+        let is_empty = clauses.is_empty();
+        formula.set_clauses(clauses);
+        formula.set_current_clause_id(self.current_clause_id);
+        if let Some(learned_clauses) = learned_clauses {
+            *learned_clauses = learned;
+        }
+
+        if is_empty {
+            return SAT::Satisfiable;
+        }
+        SAT::Unknown
+    }
+
+    /// Extends a model of the simplified formula to a model of the original formula.
+    /// The reconstruction stack is processed in reverse order: if a removed clause is not satisfied, its pivot literal is set.
+    /// The variables of the removed clauses not assigned by the model are set to false before the reconstruction.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `model` - The model of the simplified formula
+    /// 
+    pub fn extend_model(&self, model: &mut Model) {
+        for (_, clause) in self.reconstruction_stack.iter() {
+            for &literal in clause.iter() {
+                if !model.has_abs(literal.unsigned_abs()) {
+                    model.add(-literal.abs());
+                }
+            }
+        }
+        for (pivot, clause) in self.reconstruction_stack.iter().rev() {
+            if !clause.iter().any(|&literal| model.has(literal)) {
+                model.add(*pivot);
+            }
+        }
+    }
+
+    /// Returns the resolution steps of the preprocessing
+    /// 
+    /// # Returns
+    /// 
+    /// * `&Vec<(Clause, Clause, Clause)>` - The derivations, where the first two elements are the resolved clauses and the third is the resolvent
+    /// 
+    pub fn get_derivations(&self) -> &Vec<(Clause, Clause, Clause)> {
+        &self.derivations
+    }
+
+    /// Returns the number of clauses eliminated by the preprocessing
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of clauses eliminated
+    /// 
+    pub fn get_clauses_eliminated(&self) -> usize {
+        self.clauses_eliminated
+    }
+
+    /// Returns the number of variables eliminated by the preprocessing
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of variables eliminated
+    /// 
+    pub fn get_variables_eliminated(&self) -> usize {
+        self.variables_eliminated
+    }
+
+    /// Loads the clauses of the formula and the learned clauses, removing tautologies and duplicates
+    /// 
+    /// # Arguments
+    /// 
+    /// * `formula` - The formula to load
+    /// * `learned_clauses` - The learned clauses to load
+    /// 
+    fn load(&mut self, formula: &Formula, learned_clauses: &Vec<Clause>) {
+        self.clauses = Vec::new();
+        self.clause_ids = Vec::new();
+        self.is_learned = Vec::new();
+        self.removed = Vec::new();
+        self.occurrences = HashMap::new();
+        self.current_clause_id = formula.get_current_clause_id();
+
+        self.assignment = Model::new(Some(formula.get_num_variables()));
+        self.units = Vec::new();
+        self.unit_clause_ids = HashMap::new();
+        self.is_unsatisfiable = false;
+
+        self.derivations = Vec::new();
+
+        let mut loaded: HashSet<Vec<isize>> = HashSet::new();
+        for (clause, is_learned) in formula.get_clauses().iter().map(|x| (x, false)).chain(learned_clauses.iter().map(|x| (x, true))) {
+            let literals: Vec<isize> = clause.iter_literals().cloned().collect();
+            if literals.iter().any(|&literal| literals.contains(&-literal)) {
+                continue;
+            }
+            if loaded.insert(literals.clone()) {
+                self.add_clause(literals, clause.get_id());
+                *self.is_learned.last_mut().unwrap() = is_learned;
+            }
+        }
+    }
+
+    /// Counts the variables occurring in the clauses not removed
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of variables
+    /// 
+    fn count_variables(&self) -> usize {
+        let mut variables = HashSet::new();
+        for idx in 0..self.clauses.len() {
+            if !self.removed[idx] {
+                variables.extend(self.clauses[idx].iter().map(|x| x.abs()));
+            }
+        }
+        variables.len()
+    }
+
+    /// Adds a clause to the working clauses
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literals` - The literals of the clause
+    /// * `clause_id` - The id of the clause
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The index of the clause
+    /// 
+    fn add_clause(&mut self, literals: Vec<isize>, clause_id: usize) -> usize {
+        let idx = self.clauses.len();
+        for &literal in literals.iter() {
+            self.occurrences.entry(literal).or_insert_with(Vec::new).push(idx);
+        }
+        match literals.len() {
+            0 => self.is_unsatisfiable = true,
+            1 => self.units.push((literals[0], Some(clause_id))),
+            _ => (),
+        }
+        self.clauses.push(literals);
+        self.clause_ids.push(clause_id);
+        self.is_learned.push(false);
+        self.removed.push(false);
+        idx
+    }
+
+    /// Removes a literal from a clause, resolving it with a clause containing the negation of the literal
+    /// The strengthened clause gets a new id, if it becomes unit its literal is queued for propagation, if it becomes empty the formula is unsatisfiable
+    /// 
+    /// # Arguments
+    /// 
+    /// * `idx` - The index of the clause
+    /// * `literal` - The literal to remove
+    /// * `other` - The literals and the id of the clause resolved with
+    /// 
+    fn remove_literal(&mut self, idx: usize, literal: isize, other: (Vec<isize>, usize)) {
+        let parent = self.clauses[idx].clone();
+        let parent_id = self.clause_ids[idx];
+        self.clauses[idx].retain(|&x| x != literal);
+        let clause_id = self.new_clause_id();
+        self.clause_ids[idx] = clause_id;
+        self.add_derivation((parent, parent_id), other, (self.clauses[idx].clone(), clause_id));
+        match self.clauses[idx].len() {
+            0 => self.is_unsatisfiable = true,
+            1 => self.units.push((self.clauses[idx][0], Some(clause_id))),
+            _ => (),
+        }
+    }
+
+    /// Returns a new clause id
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The new id
+    /// 
+    fn new_clause_id(&mut self) -> usize {
+        self.current_clause_id += 1;
+        self.current_clause_id
+    }
+
+    /// Keeps a resolution step for the proof files
+    /// 
+    /// # Arguments
+    /// 
+    /// * `first` - The literals and the id of the first resolved clause
+    /// * `second` - The literals and the id of the second resolved clause
+    /// * `resolvent` - The literals and the id of the resolvent
+    /// 
+    fn add_derivation(&mut self, first: (Vec<isize>, usize), second: (Vec<isize>, usize), resolvent: (Vec<isize>, usize)) {
+        let mut clauses: Vec<Clause> = Vec::new();
+        for (literals, clause_id) in [first, second, resolvent] {
+            let mut clause = Clause::new();
+            clause.load_vec(literals);
+            clause.set_id(clause_id);
+            clauses.push(clause);
+        }
+        let resolvent = clauses.pop().unwrap();
+        let second = clauses.pop().unwrap();
+        let first = clauses.pop().unwrap();
+        self.derivations.push((first, second, resolvent));
+    }
+
+    /// Returns the clauses not removed that contain a literal
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literal` - The literal
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<usize>` - The indexes of the clauses
+    /// 
+    fn occurrences_of(&self, literal: isize) -> Vec<usize> {
+        match self.occurrences.get(&literal) {
+            Some(occurrences) => occurrences.iter()
+                .filter(|&&idx| !self.removed[idx] && self.clauses[idx].contains(&literal))
+                .cloned()
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Propagates the queued unit literals at top level.
+    /// The clauses satisfied by a unit are removed, its negation is removed from the other clauses.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - false if a conflict is found, true otherwise
+    /// 
+    fn propagate_units(&mut self) -> bool {
+        while let Some((unit, unit_clause_id)) = self.units.pop() {
+            if self.is_unsatisfiable {
+                return false;
+            }
+            match self.assignment.satisfies(unit) {
+                SAT::Satisfiable => continue,
+                SAT::Unsatisfiable => {
+                    if let (Some(unit_clause_id), Some(&other_id)) = (unit_clause_id, self.unit_clause_ids.get(&unit.unsigned_abs())) {
+                        let clause_id = self.new_clause_id();
+                        self.add_derivation((vec![unit], unit_clause_id), (vec![-unit], other_id), (Vec::new(), clause_id));
+                    }
+                    self.is_unsatisfiable = true;
+                    return false;
+                },
+                SAT::Unknown => (),
+            }
+            self.assignment.add(unit);
+            self.reconstruction_stack.push((unit, vec![unit]));
+            for idx in self.occurrences_of(unit) {
+                self.removed[idx] = true;
+            }
+            if let Some(unit_clause_id) = unit_clause_id {
+                self.unit_clause_ids.insert(unit.unsigned_abs(), unit_clause_id);
+                for idx in self.occurrences_of(-unit) {
+                    self.remove_literal(idx, -unit, (vec![unit], unit_clause_id));
+                }
+            }
+        }
+        !self.is_unsatisfiable
+    }
+
+    /// Queues the pure literals, the literals whose negation does not occur in any clause.
+    /// They are propagated as units, so every clause containing them is removed.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - true if a pure literal is found, false otherwise
+    /// 
+    fn eliminate_pure_literals(&mut self) -> bool {
+        let mut literals: HashSet<isize> = HashSet::new();
+        for idx in 0..self.clauses.len() {
+            if !self.removed[idx] {
+                literals.extend(self.clauses[idx].iter());
+            }
+        }
+        let mut pure_literals: Vec<isize> = literals.iter()
+            .filter(|&&literal| !literals.contains(&-literal))
+            .cloned()
+            .collect();
+        pure_literals.sort();
+        let found = !pure_literals.is_empty();
+        self.units.extend(pure_literals.iter().map(|&literal| (literal, None)));
+        found
+    }
+
+    /// Applies subsumption and self-subsuming resolution.
+    /// Every clause, from the shortest, removes the clauses it subsumes (backward subsumption)
+    /// and strengthens the clauses it can resolve with such that the resolvent subsumes them.
+    /// A strengthened clause is then checked against the other clauses (forward subsumption).
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - true if a clause is removed or strengthened, false otherwise
+    /// 
+    fn subsume(&mut self) -> bool {
+        let mut changed = false;
+
+        let mut order: Vec<usize> = (0..self.clauses.len()).filter(|&idx| !self.removed[idx]).collect();
+        order.sort_by_key(|&idx| self.clauses[idx].len());
+
+        for idx in order {
+            if self.removed[idx] || self.is_unsatisfiable {
+                continue;
+            }
+            let clause = self.clauses[idx].clone();
+
+            // backward subsumption
+            let literal = *clause.iter().min_by_key(|&&literal| self.occurrences_of(literal).len()).unwrap();
+            for other_idx in self.occurrences_of(literal) {
+                if other_idx != idx && Self::is_subset(&clause, &self.clauses[other_idx]) {
+                    self.removed[other_idx] = true;
+                    changed = true;
+                }
+            }
+
+            // self-subsuming resolution
+            for &literal in clause.iter() {
+                for other_idx in self.occurrences_of(-literal) {
+                    if other_idx == idx {
+                        continue;
+                    }
+                    let is_strengthened = clause.iter()
+                        .filter(|&&x| x != literal)
+                        .all(|x| self.clauses[other_idx].contains(x));
+                    if is_strengthened {
+                        self.remove_literal(other_idx, -literal, (clause.clone(), self.clause_ids[idx]));
+                        changed = true;
+                        if self.is_forward_subsumed(other_idx) {
+                            self.removed[other_idx] = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        changed
+    }
+
+    /// Applies failed literal probing.
+    /// Every literal is assumed and propagated: if a conflict is found, its negation is a unit.
+    /// If both a literal and its negation imply the same literal, the implied literal is a unit.
+    /// The units are derived by resolution along the propagated literals, then they are propagated before going on.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - true if a unit is found, false otherwise
+    /// 
+    fn probe(&mut self) -> bool {
+        if !self.probing {
+            return false;
+        }
+
+        let mut changed = false;
+
+        for variable in 1..=self.assignment.len() {
+            if self.is_unsatisfiable {
+                break;
+            }
+            let variable = variable as isize;
+            if self.assignment.has_abs(variable.unsigned_abs()) || self.eliminated_variables.contains(&variable.unsigned_abs()) {
+                continue;
+            }
+            if self.occurrences_of(variable).is_empty() && self.occurrences_of(-variable).is_empty() {
+                continue;
+            }
+
+            let (positive_trail, positive_conflict) = self.probe_literal(variable);
+            if let Some(conflict_idx) = positive_conflict {
+                let (literals, clause_id) = self.derive_from_probe(&positive_trail, conflict_idx);
+                self.add_clause(literals, clause_id);
+                self.propagate_units();
+                changed = true;
+                continue;
+            }
+            let (negative_trail, negative_conflict) = self.probe_literal(-variable);
+            if let Some(conflict_idx) = negative_conflict {
+                let (literals, clause_id) = self.derive_from_probe(&negative_trail, conflict_idx);
+                self.add_clause(literals, clause_id);
+                self.propagate_units();
+                changed = true;
+                continue;
+            }
+
+            for &(literal, positive_reason) in positive_trail.iter().skip(1) {
+                let negative_reason = negative_trail.iter().find(|&&(x, _)| x == literal).map(|&(_, reason)| reason);
+                if let (Some(positive_idx), Some(Some(negative_idx))) = (positive_reason, negative_reason) {
+                    let (positive_literals, positive_id) = self.derive_from_probe(&positive_trail, positive_idx);
+                    let (negative_literals, negative_id) = self.derive_from_probe(&negative_trail, negative_idx);
+                    let (literals, clause_id) = if !positive_literals.contains(&-variable) {
+                        (positive_literals, positive_id)
+                    } else if !negative_literals.contains(&variable) {
+                        (negative_literals, negative_id)
+                    } else {
+                        let resolvent = Self::resolve(&negative_literals, &positive_literals, variable).unwrap();
+                        let clause_id = self.new_clause_id();
+                        self.add_derivation((negative_literals, negative_id), (positive_literals, positive_id), (resolvent.clone(), clause_id));
+                        (resolvent, clause_id)
+                    };
+                    self.add_clause(literals, clause_id);
+                    changed = true;
+                }
+            }
+            if !self.propagate_units() {
+                break;
+            }
+        }
+
+        changed
+    }
+
+    /// Assumes a literal and propagates it over the working clauses, without changing them
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literal` - The literal to assume
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<(isize, Option<usize>)>` - The propagated literals, with the index of the clause that propagated them (None for the assumed literal)
+    /// * `Option<usize>` - The index of the conflict clause, if there is one
+    /// 
+    fn probe_literal(&self, literal: isize) -> (Vec<(isize, Option<usize>)>, Option<usize>) {
+        let mut assignment = self.assignment.clone();
+        assignment.add(literal);
+        let mut trail: Vec<(isize, Option<usize>)> = vec![(literal, None)];
+
+        let mut head = 0;
+        while head < trail.len() {
+            let assigned = trail[head].0;
+            head += 1;
+            for idx in self.occurrences_of(-assigned) {
+                let mut unassigned: Vec<isize> = Vec::new();
+                let mut is_satisfied = false;
+                for &x in self.clauses[idx].iter() {
+                    match assignment.satisfies(x) {
+                        SAT::Satisfiable => {
+                            is_satisfied = true;
+                            break;
+                        },
+                        SAT::Unknown => unassigned.push(x),
+                        SAT::Unsatisfiable => (),
+                    }
+                }
+                if is_satisfied {
+                    continue;
+                }
+                match unassigned.len() {
+                    0 => return (trail, Some(idx)),
+                    1 => {
+                        assignment.add(unassigned[0]);
+                        trail.push((unassigned[0], Some(idx)));
+                    },
+                    _ => (),
+                }
+            }
+        }
+
+        (trail, None)
+    }
+
+    /// Derives a clause by resolving a clause with the clauses that propagated its false literals during a probe
+    /// 
+    /// # Arguments
+    /// 
+    /// * `trail` - The propagated literals of the probe
+    /// * `clause_idx` - The index of the clause to start from
+    /// 
+    /// # Returns
+    /// 
+    /// * `(Vec<isize>, usize)` - The literals and the id of the derived clause, it contains only the negation of the assumed literal and the literals true in the probe
+    /// 
+    fn derive_from_probe(&mut self, trail: &Vec<(isize, Option<usize>)>, clause_idx: usize) -> (Vec<isize>, usize) {
+        let mut literals = self.clauses[clause_idx].clone();
+        let mut clause_id = self.clause_ids[clause_idx];
+
+        for &(literal, reason) in trail.iter().rev() {
+            if let Some(reason_idx) = reason {
+                if !literals.contains(&-literal) {
+                    continue;
+                }
+                if let Some(resolvent) = Self::resolve(&self.clauses[reason_idx], &literals, literal) {
+                    let resolvent_id = self.new_clause_id();
+                    self.add_derivation(
+                        (self.clauses[reason_idx].clone(), self.clause_ids[reason_idx]),
+                        (literals, clause_id),
+                        (resolvent.clone(), resolvent_id)
+                    );
+                    literals = resolvent;
+                    clause_id = resolvent_id;
+                }
+            }
+        }
+
+        (literals, clause_id)
+    }
+
+    /// Applies equivalent literal substitution.
+    /// The strongly connected components of the binary implication graph are equivalent literals:
+    /// every variable is replaced by the literal with the smallest variable of its component, in every clause.
+    /// The binary clauses used for the substitution are derived by resolution along the implication paths.
+    /// If a literal is equivalent to its negation, the formula is unsatisfiable.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - true if a variable is substituted, false otherwise
+    /// 
+    fn substitute_equivalent_literals(&mut self) -> bool {
+        if !self.probing {
+            return false;
+        }
+
+        let num_nodes = self.assignment.len() * 2;
+        let mut edges: Vec<Vec<(usize, usize)>> = vec![Vec::new(); num_nodes];
+        for idx in 0..self.clauses.len() {
+            if !self.removed[idx] && self.clauses[idx].len() == 2 {
+                let (a, b) = (self.clauses[idx][0], self.clauses[idx][1]);
+                edges[Self::literal_node(-a)].push((Self::literal_node(b), idx));
+                edges[Self::literal_node(-b)].push((Self::literal_node(a), idx));
+            }
+        }
+
+        let components = Self::strongly_connected_components(&edges);
+        let mut members: HashMap<usize, Vec<isize>> = HashMap::new();
+        for node in 0..num_nodes {
+            members.entry(components[node]).or_insert_with(Vec::new).push(Self::node_literal(node));
+        }
+
+        let mut changed = false;
+
+        for variable in 1..=self.assignment.len() {
+            let variable = variable as isize;
+            let component = &members[&components[Self::literal_node(variable)]];
+            if component.len() < 2 {
+                continue;
+            }
+            if component.contains(&-variable) {
+                let (positive_literals, positive_id) = self.derive_implication(&edges, variable, -variable);
+                let (negative_literals, negative_id) = self.derive_implication(&edges, -variable, variable);
+                let clause_id = self.new_clause_id();
+                self.add_derivation((negative_literals, negative_id), (positive_literals, positive_id), (Vec::new(), clause_id));
+                self.is_unsatisfiable = true;
+                return true;
+            }
+            let representative = *component.iter().min_by_key(|x| x.abs()).unwrap();
+            if representative == variable {
+                continue;
+            }
+
+            let (implication_literals, implication_id) = self.derive_implication(&edges, variable, representative);
+            let (reverse_literals, reverse_id) = self.derive_implication(&edges, -variable, -representative);
+
+            for (literal, (other_literals, other_id)) in [(variable, (implication_literals, implication_id)), (-variable, (reverse_literals, reverse_id))] {
+                for idx in self.occurrences_of(literal) {
+                    self.removed[idx] = true;
+                    if let Some(resolvent) = Self::resolve(&self.clauses[idx], &other_literals, literal) {
+                        let clause_id = self.new_clause_id();
+                        self.add_derivation(
+                            (self.clauses[idx].clone(), self.clause_ids[idx]),
+                            (other_literals.clone(), other_id),
+                            (resolvent.clone(), clause_id)
+                        );
+                        let is_learned = self.is_learned[idx];
+                        let new_idx = self.add_clause(resolvent, clause_id);
+                        self.is_learned[new_idx] = is_learned;
+                    }
+                }
+            }
+
+            self.reconstruction_stack.push((variable, vec![variable, -representative]));
+            self.reconstruction_stack.push((-variable, vec![-variable, representative]));
+            self.eliminated_variables.insert(variable.unsigned_abs());
+            changed = true;
+        }
+
+        changed
+    }
+
+    /// Derives the binary clause of an implication between two literals, resolving the binary clauses along the shortest implication path
+    /// 
+    /// # Arguments
+    /// 
+    /// * `edges` - The binary implication graph, with the index of the clause of every edge
+    /// * `from` - The implying literal
+    /// * `to` - The implied literal
+    /// 
+    /// # Returns
+    /// 
+    /// * `(Vec<isize>, usize)` - The literals and the id of the derived clause
+    /// 
+    fn derive_implication(&mut self, edges: &Vec<Vec<(usize, usize)>>, from: isize, to: isize) -> (Vec<isize>, usize) {
+        let from_node = Self::literal_node(from);
+        let to_node = Self::literal_node(to);
+
+        let mut parents: Vec<Option<(usize, usize)>> = vec![None; edges.len()];
+        let mut visited = vec![false; edges.len()];
+        let mut queue = std::collections::VecDeque::from(vec![from_node]);
+        visited[from_node] = true;
+        while let Some(node) = queue.pop_front() {
+            if node == to_node {
+                break;
+            }
+            for &(next, idx) in edges[node].iter() {
+                if !visited[next] {
+                    visited[next] = true;
+                    parents[next] = Some((node, idx));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let mut path: Vec<(usize, usize)> = Vec::new();
+        let mut node = to_node;
+        while let Some((parent, idx)) = parents[node] {
+            path.push((node, idx));
+            node = parent;
+        }
+        path.reverse();
+
+        let mut literals = self.clauses[path[0].1].clone();
+        let mut clause_id = self.clause_ids[path[0].1];
+        for window in path.windows(2) {
+            let pivot = Self::node_literal(window[0].0);
+            let idx = window[1].1;
+            let resolvent = Self::resolve(&literals, &self.clauses[idx], pivot).unwrap();
+            let resolvent_id = self.new_clause_id();
+            self.add_derivation((literals, clause_id), (self.clauses[idx].clone(), self.clause_ids[idx]), (resolvent.clone(), resolvent_id));
+            literals = resolvent;
+            clause_id = resolvent_id;
+        }
+
+        (literals, clause_id)
+    }
+
+    /// Computes the strongly connected components of a graph with the Tarjan algorithm
+    /// 
+    /// # Arguments
+    /// 
+    /// * `edges` - The edges of every node
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<usize>` - The component of every node
+    /// 
+    pub(crate) fn strongly_connected_components(edges: &Vec<Vec<(usize, usize)>>) -> Vec<usize> {
+        let num_nodes = edges.len();
+        let mut index = vec![usize::MAX; num_nodes];
+        let mut lowlink = vec![0; num_nodes];
+        let mut on_stack = vec![false; num_nodes];
+        let mut stack: Vec<usize> = Vec::new();
+        let mut components = vec![usize::MAX; num_nodes];
+        let mut current_index = 0;
+        let mut current_component = 0;
+
+        for start in 0..num_nodes {
+            if index[start] != usize::MAX {
+                continue;
+            }
+            let mut call_stack: Vec<(usize, usize)> = vec![(start, 0)];
+            index[start] = current_index;
+            lowlink[start] = current_index;
+            current_index += 1;
+            stack.push(start);
+            on_stack[start] = true;
+
+            while let Some(&(node, edge_idx)) = call_stack.last() {
+                if edge_idx < edges[node].len() {
+                    call_stack.last_mut().unwrap().1 += 1;
+                    let next = edges[node][edge_idx].0;
+                    if index[next] == usize::MAX {
+                        index[next] = current_index;
+                        lowlink[next] = current_index;
+                        current_index += 1;
+                        stack.push(next);
+                        on_stack[next] = true;
+                        call_stack.push((next, 0));
+                    } else if on_stack[next] {
+                        lowlink[node] = lowlink[node].min(index[next]);
+                    }
+                } else {
+                    call_stack.pop();
+                    if let Some(&(parent, _)) = call_stack.last() {
+                        lowlink[parent] = lowlink[parent].min(lowlink[node]);
+                    }
+                    if lowlink[node] == index[node] {
+                        while let Some(member) = stack.pop() {
+                            on_stack[member] = false;
+                            components[member] = current_component;
+                            if member == node {
+                                break;
+                            }
+                        }
+                        current_component += 1;
+                    }
+                }
+            }
+        }
+
+        components
+    }
+
+    /// Returns the node of a literal in the implication graph
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literal` - The literal
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The node
+    /// 
+    pub(crate) fn literal_node(literal: isize) -> usize {
+        (literal.unsigned_abs() - 1) * 2 + if literal < 0 { 1 } else { 0 }
+    }
+
+    /// Returns the literal of a node in the implication graph
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node` - The node
+    /// 
+    /// # Returns
+    /// 
+    /// * `isize` - The literal
+    /// 
+    pub(crate) fn node_literal(node: usize) -> isize {
+        let variable = (node / 2 + 1) as isize;
+        if node % 2 == 1 {
+            -variable
+        } else {
+            variable
+        }
+    }
+
+    /// Applies bounded variable elimination.
+    /// A variable is eliminated by replacing the clauses containing it with all their non-tautological resolvents on it,
+    /// only if the number of clauses does not grow. The removed clauses are pushed on the reconstruction stack.
+    /// It stops as soon as a resolvent is a unit clause, so that it is propagated before going on.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - true if a variable is eliminated, false otherwise
+    /// 
+    fn eliminate_variables(&mut self) -> bool {
+        if !self.variable_elimination {
+            return false;
+        }
+
+        let mut changed = false;
+
+        let mut candidates: Vec<(isize, usize)> = Vec::new();
+        for variable in 1..=self.assignment.len() {
+            if self.assignment.has_abs(variable) || self.eliminated_variables.contains(&variable) {
+                continue;
+            }
+            let positive = self.occurrences_of(variable as isize).len();
+            let negative = self.occurrences_of(-(variable as isize)).len();
+            if positive > 0 && negative > 0 && positive + negative <= preprocessing::MAX_ELIMINATION_OCCURRENCES {
+                candidates.push((variable as isize, positive * negative));
+            }
+        }
+        candidates.sort_by_key(|&(_, cost)| cost);
+
+        for (variable, _) in candidates {
+            if self.is_unsatisfiable || !self.units.is_empty() {
+                break;
+            }
+
+            let positive = self.occurrences_of(variable);
+            let negative = self.occurrences_of(-variable);
+            if positive.is_empty() || negative.is_empty() {
+                continue;
+            }
+
+            let mut resolvents: Vec<(usize, usize, Vec<isize>)> = Vec::new();
+            let mut is_bounded = true;
+            'resolution_loop: for &positive_idx in positive.iter() {
+                for &negative_idx in negative.iter() {
+                    if let Some(resolvent) = Self::resolve(&self.clauses[positive_idx], &self.clauses[negative_idx], variable) {
+                        if resolvent.len() > preprocessing::MAX_RESOLVENT_LENGTH {
+                            is_bounded = false;
+                            break 'resolution_loop;
+                        }
+                        resolvents.push((positive_idx, negative_idx, resolvent));
+                        if resolvents.len() > positive.len() + negative.len() {
+                            is_bounded = false;
+                            break 'resolution_loop;
+                        }
+                    }
+                }
+            }
+            if !is_bounded {
+                continue;
+            }
+
+            for &idx in positive.iter() {
+                self.reconstruction_stack.push((variable, self.clauses[idx].clone()));
+                self.removed[idx] = true;
+            }
+            for &idx in negative.iter() {
+                self.reconstruction_stack.push((-variable, self.clauses[idx].clone()));
+                self.removed[idx] = true;
+            }
+            for (positive_idx, negative_idx, resolvent) in resolvents {
+                let clause_id = self.new_clause_id();
+                self.add_derivation(
+                    (self.clauses[positive_idx].clone(), self.clause_ids[positive_idx]),
+                    (self.clauses[negative_idx].clone(), self.clause_ids[negative_idx]),
+                    (resolvent.clone(), clause_id)
+                );
+                self.add_clause(resolvent, clause_id);
+            }
+
+            self.eliminated_variables.insert(variable.unsigned_abs());
+            changed = true;
+        }
+
+        changed
+    }
+
+    /// Applies blocked clause elimination and, if enabled, covered clause elimination.
+    /// A clause is blocked on one of its literals if all its resolvents on that literal are tautologies, so it can be removed.
+    /// With covered clause elimination the clause is first extended with its covered literals, the literals contained
+    /// in every non-tautological resolvent on one of its literals, then it is checked again.
+    /// Every extension step and the blocked clause are pushed on the reconstruction stack with their literal as pivot:
+    /// while repairing a model, the extended clause is satisfied first, then the steps are undone one by one.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - true if a clause is eliminated, false otherwise
+    /// 
+    fn eliminate_blocked_clauses(&mut self) -> bool {
+        if !self.blocked_clause_elimination {
+            return false;
+        }
+
+        let mut changed = false;
+
+        for idx in 0..self.clauses.len() {
+            if self.removed[idx] || self.is_learned[idx] {
+                continue;
+            }
+
+            let mut covered_clause = self.clauses[idx].clone();
+            let mut steps: Vec<(isize, Vec<isize>)> = Vec::new();
+            let mut is_blocked = false;
+
+            'covered_loop: loop {
+                for &literal in covered_clause.clone().iter() {
+                    let candidates: Vec<usize> = self.occurrences_of(-literal).into_iter()
+                        .filter(|&other_idx| other_idx != idx && Self::resolve(&covered_clause, &self.clauses[other_idx], literal).is_some())
+                        .collect();
+
+                    if candidates.is_empty() {
+                        steps.push((literal, covered_clause.clone()));
+                        is_blocked = true;
+                        break 'covered_loop;
+                    }
+                    if !self.covered_clause_elimination {
+                        continue;
+                    }
+
+                    let mut covered_literals: Vec<isize> = self.clauses[candidates[0]].iter()
+                        .filter(|&&x| x != -literal && !covered_clause.contains(&x))
+                        .cloned()
+                        .collect();
+                    for &other_idx in candidates.iter().skip(1) {
+                        covered_literals.retain(|x| self.clauses[other_idx].contains(x));
+                    }
+                    if covered_literals.is_empty() {
+                        continue;
+                    }
+
+                    steps.push((literal, covered_clause.clone()));
+                    covered_clause.extend(covered_literals);
+                    covered_clause.sort_by(|a, b| a.abs().cmp(&b.abs()));
+                    if covered_clause.len() > preprocessing::MAX_COVERED_CLAUSE_LENGTH {
+                        break 'covered_loop;
+                    }
+                    continue 'covered_loop;
+                }
+                break;
+            }
+
+            if is_blocked {
+                self.reconstruction_stack.extend(steps);
+                self.removed[idx] = true;
+                changed = true;
+            }
+        }
+
+        changed
+    }
+
+    /// Resolves two clauses on a variable
+    /// 
+    /// # Arguments
+    /// 
+    /// * `positive` - The literals of the clause containing the variable
+    /// * `negative` - The literals of the clause containing the negation of the variable
+    /// * `variable` - The variable to resolve on
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<Vec<isize>>` - The resolvent ordered by absolute value, None if it is a tautology
+    /// 
+    fn resolve(positive: &Vec<isize>, negative: &Vec<isize>, variable: isize) -> Option<Vec<isize>> {
+        let mut resolvent: Vec<isize> = positive.iter().filter(|&&x| x != variable).cloned().collect();
+        for &literal in negative.iter() {
+            if literal == -variable || resolvent.contains(&literal) {
+                continue;
+            }
+            if resolvent.contains(&-literal) {
+                return None;
+            }
+            resolvent.push(literal);
+        }
+        resolvent.sort_by(|a, b| a.abs().cmp(&b.abs()));
+        Some(resolvent)
+    }
+
+    /// Checks if a clause is subsumed by another clause not removed
+    /// 
+    /// # Arguments
+    /// 
+    /// * `idx` - The index of the clause
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - true if the clause is subsumed, false otherwise
+    /// 
+    fn is_forward_subsumed(&self, idx: usize) -> bool {
+        let clause = &self.clauses[idx];
+        for &literal in clause.iter() {
+            for other_idx in self.occurrences_of(literal) {
+                if other_idx != idx && Self::is_subset(&self.clauses[other_idx], clause) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Checks if every literal of a clause is contained in another clause
+    /// 
+    /// # Arguments
+    /// 
+    /// * `clause` - The literals of the clause
+    /// * `other` - The literals of the other clause
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - true if the first clause is a subset of the second, false otherwise
+    /// 
+    fn is_subset(clause: &Vec<isize>, other: &Vec<isize>) -> bool {
+        clause.len() <= other.len() && clause.iter().all(|literal| other.contains(literal))
+    }
+}
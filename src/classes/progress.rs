@@ -0,0 +1,177 @@
+use std::io::{stdout, Write};
+use std::time::{Duration, Instant};
+
+use crossterm::{cursor, execute, terminal};
+
+use crate::classes::stats::Stats;
+
+pub struct Progress {
+    conflict_interval: Option<usize>,
+    time_interval: Option<Duration>,
+    live: bool,
+
+    start_time: Option<Instant>,
+    last_report: Instant,
+    next_conflicts: usize,
+    lines_printed: usize,
+}
+
+impl Progress {
+    pub fn new() -> Progress {
+        Progress {
+            conflict_interval: None,
+            time_interval: None,
+            live: false,
+
+            start_time: None,
+            last_report: Instant::now(),
+            next_conflicts: 0,
+            lines_printed: 0,
+        }
+    }
+
+    /// Starts the progress reporting, if it is not already running.
+    pub fn start(&mut self) {
+        if self.start_time.is_none() {
+            self.start_time = Some(Instant::now());
+            self.last_report = Instant::now();
+            self.next_conflicts = self.conflict_interval.unwrap_or(0);
+            self.lines_printed = 0;
+        }
+    }
+
+    /// Stops the progress reporting.
+    pub fn reset(&mut self) {
+        self.start_time = None;
+    }
+
+    /// Returns the number of conflicts between two reports
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<usize>` - The number of conflicts, None if the reports are not based on conflicts
+    /// 
+    pub fn get_conflict_interval(&self) -> Option<usize> {
+        self.conflict_interval
+    }
+
+    /// Sets the number of conflicts between two reports
+    /// 
+    /// # Arguments
+    /// 
+    /// * `conflict_interval` - The number of conflicts, None to disable the reports based on conflicts
+    /// 
+    pub fn set_conflict_interval(&mut self, conflict_interval: Option<usize>) {
+        self.conflict_interval = conflict_interval.filter(|&conflicts| conflicts > 0);
+    }
+
+    /// Returns the time between two reports
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<Duration>` - The time, None if the reports are not based on time
+    /// 
+    pub fn get_time_interval(&self) -> Option<Duration> {
+        self.time_interval
+    }
+
+    /// Sets the time between two reports
+    /// 
+    /// # Arguments
+    /// 
+    /// * `time_interval` - The time, None to disable the reports based on time
+    /// 
+    pub fn set_time_interval(&mut self, time_interval: Option<Duration>) {
+        self.time_interval = time_interval;
+    }
+
+    /// Sets the reports to be a live panel updated in place, instead of comment lines
+    /// 
+    /// # Arguments
+    /// 
+    /// * `live` - True for the live panel of the interactive mode, false for the comment lines
+    /// 
+    pub fn set_live(&mut self, live: bool) {
+        self.live = live;
+    }
+
+    /// Checks if a report is due
+    /// 
+    /// # Arguments
+    /// 
+    /// * `stats` - The statistics of the solver
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - true if the interval of conflicts or time is elapsed since the last report, false otherwise
+    /// 
+    pub fn is_due(&self, stats: &Stats) -> bool {
+        if self.start_time.is_none() {
+            return false;
+        }
+        if self.conflict_interval.is_some() && stats.get_conflicts() >= self.next_conflicts {
+            return true;
+        }
+        if let Some(time_interval) = self.time_interval {
+            if self.last_report.elapsed() >= time_interval {
+                return true;
+            }
+        }
+        return false;
+    }
+
+    /// Prints a report
+    /// 
+    /// # Arguments
+    /// 
+    /// * `stats` - The statistics of the solver
+    /// * `learned_clauses` - The number of learned clauses in the database
+    /// * `average_length` - The average length of the learned clauses in the database
+    /// 
+    pub fn report(&mut self, stats: &Stats, learned_clauses: usize, average_length: f64) {
+        let elapsed = match self.start_time {
+            Some(start_time) => start_time.elapsed().as_secs_f64(),
+            None => 0.0,
+        };
+        let row = format!(
+            "| {:>10} | {:>10} | {:>9} | {:>8.2} | {:>8} | {:>12} | {:>8.2}s |",
+            stats.get_conflicts(),
+            stats.get_decisions(),
+            learned_clauses,
+            average_length,
+            stats.get_restarts(),
+            stats.get_physical_memory(),
+            elapsed
+        );
+        let header = "|  Conflicts |  Decisions |   Learned |  Lits/Cl | Restarts |       Memory |      Time |";
+        let separator = "=".repeat(header.len());
+
+        if self.live {
+            let mut stdout = stdout();
+            if self.lines_printed > 0 {
+                let _ = execute!(stdout, cursor::MoveUp(self.lines_printed as u16), terminal::Clear(terminal::ClearType::FromCursorDown));
+            }
+            println!("{}", separator);
+            println!("{}", header);
+            println!("{}", row);
+            println!("{}", separator);
+            let _ = stdout.flush();
+            self.lines_printed = 4;
+        } else {
+            if self.lines_printed == 0 {
+                println!("c {}", separator);
+                println!("c {}", header);
+                println!("c {}", separator);
+            }
+            println!("c {}", row);
+            self.lines_printed += 1;
+        }
+
+        self.last_report = Instant::now();
+        if let Some(conflict_interval) = self.conflict_interval {
+            while self.next_conflicts <= stats.get_conflicts() {
+                self.next_conflicts += conflict_interval;
+            }
+        }
+    }
+}
\ No newline at end of file
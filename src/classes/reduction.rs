@@ -0,0 +1,80 @@
+pub mod schedule;
+pub mod length;
+pub mod activity;
+pub mod lbd;
+pub mod age;
+pub mod keep_all;
+
+use std::collections::HashSet;
+
+use crate::classes::clause::Clause;
+
+/// A deletion policy of the learned clauses
+/// When the database of the learned clauses is full, the solver asks the policy which clauses to delete.
+/// The clauses that are the reason of an assigned literal are locked, the solver never deletes them.
+pub trait ReductionPolicy: Send {
+    /// Returns the name of the policy
+    /// 
+    /// # Returns
+    /// 
+    /// * `&'static str` - The name, one of the names in `consts::reduction::POLICIES`
+    /// 
+    fn name(&self) -> &'static str;
+
+    /// Selects the learned clauses to delete
+    /// 
+    /// # Arguments
+    /// 
+    /// * `learned_clauses` - The learned clauses, from the oldest to the newest
+    /// * `locked` - The indexes of the learned clauses that cannot be deleted
+    /// * `target` - The number of clauses the schedule asks to delete
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<usize>` - The indexes of the clauses to delete, at most `target`
+    /// 
+    fn select(&self, learned_clauses: &Vec<Clause>, locked: &HashSet<usize>, target: usize) -> Vec<usize>;
+}
+
+/// Creates a deletion policy from its name
+/// 
+/// # Arguments
+/// 
+/// * `name` - The name of the policy, ignoring the case
+/// 
+/// # Returns
+/// 
+/// * `Option<Box<dyn ReductionPolicy>>` - The policy, None if the name is unknown
+/// 
+pub fn from_name(name: &str) -> Option<Box<dyn ReductionPolicy>> {
+    match name.to_lowercase().as_str() {
+        "length" => Some(Box::new(length::Length {})),
+        "activity" => Some(Box::new(activity::Activity {})),
+        "lbd" => Some(Box::new(lbd::Lbd {})),
+        "age" => Some(Box::new(age::Age {})),
+        "keep all" | "keep-all" | "none" => Some(Box::new(keep_all::KeepAll {})),
+        _ => None,
+    }
+}
+
+/// Selects the unlocked clauses with the lowest scores
+/// 
+/// # Arguments
+/// 
+/// * `learned_clauses` - The learned clauses
+/// * `locked` - The indexes of the learned clauses that cannot be deleted
+/// * `target` - The number of clauses to select
+/// * `score` - The score of a clause, the clauses with a lower score are deleted first, None if the clause must be kept
+/// 
+/// # Returns
+/// 
+/// * `Vec<usize>` - The indexes of the selected clauses, the older clauses are selected first on ties
+/// 
+pub fn lowest_scores(learned_clauses: &Vec<Clause>, locked: &HashSet<usize>, target: usize, score: impl Fn(&Clause) -> Option<f64>) -> Vec<usize> {
+    let mut candidates: Vec<(usize, f64)> = learned_clauses.iter().enumerate()
+        .filter(|(idx, _)| !locked.contains(idx))
+        .filter_map(|(idx, clause)| score(clause).map(|score| (idx, score)))
+        .collect();
+    candidates.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+    return candidates.into_iter().take(target).map(|(idx, _)| idx).collect();
+}
\ No newline at end of file
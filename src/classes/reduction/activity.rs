@@ -0,0 +1,18 @@
+use std::collections::HashSet;
+
+use crate::classes::clause::Clause;
+use crate::classes::reduction::{self, ReductionPolicy};
+
+/// Deletion by activity
+/// The clauses least used by the recent conflict analyses are deleted.
+pub struct Activity {}
+
+impl ReductionPolicy for Activity {
+    fn name(&self) -> &'static str {
+        "Activity"
+    }
+
+    fn select(&self, learned_clauses: &Vec<Clause>, locked: &HashSet<usize>, target: usize) -> Vec<usize> {
+        reduction::lowest_scores(learned_clauses, locked, target, |clause| Some(clause.get_activity()))
+    }
+}
\ No newline at end of file
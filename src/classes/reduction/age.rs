@@ -0,0 +1,18 @@
+use std::collections::HashSet;
+
+use crate::classes::clause::Clause;
+use crate::classes::reduction::{self, ReductionPolicy};
+
+/// Deletion by age
+/// The oldest clauses are deleted first.
+pub struct Age {}
+
+impl ReductionPolicy for Age {
+    fn name(&self) -> &'static str {
+        "Age"
+    }
+
+    fn select(&self, learned_clauses: &Vec<Clause>, locked: &HashSet<usize>, target: usize) -> Vec<usize> {
+        reduction::lowest_scores(learned_clauses, locked, target, |_| Some(0.0))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,17 @@
+use std::collections::HashSet;
+
+use crate::classes::clause::Clause;
+use crate::classes::reduction::ReductionPolicy;
+
+/// No deletion, every learned clause is kept
+pub struct KeepAll {}
+
+impl ReductionPolicy for KeepAll {
+    fn name(&self) -> &'static str {
+        "Keep all"
+    }
+
+    fn select(&self, _learned_clauses: &Vec<Clause>, _locked: &HashSet<usize>, _target: usize) -> Vec<usize> {
+        Vec::new()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,22 @@
+use std::collections::HashSet;
+
+use crate::consts::reduction::GLUE_LBD;
+use crate::classes::clause::Clause;
+use crate::classes::reduction::{self, ReductionPolicy};
+
+/// Deletion by literal block distance
+/// The clauses with the highest LBD are deleted, the glue clauses are always kept.
+pub struct Lbd {}
+
+impl ReductionPolicy for Lbd {
+    fn name(&self) -> &'static str {
+        "LBD"
+    }
+
+    fn select(&self, learned_clauses: &Vec<Clause>, locked: &HashSet<usize>, target: usize) -> Vec<usize> {
+        reduction::lowest_scores(learned_clauses, locked, target, |clause| match clause.get_lbd() > GLUE_LBD {
+            true => Some(-(clause.get_lbd() as f64)),
+            false => None,
+        })
+    }
+}
\ No newline at end of file
@@ -0,0 +1,24 @@
+use std::collections::HashSet;
+
+use crate::classes::clause::Clause;
+use crate::classes::reduction::ReductionPolicy;
+
+/// Deletion by length
+/// Among the oldest learned clauses, as many as the target, the clauses longer than the average are deleted.
+pub struct Length {}
+
+impl ReductionPolicy for Length {
+    fn name(&self) -> &'static str {
+        "Length"
+    }
+
+    fn select(&self, learned_clauses: &Vec<Clause>, locked: &HashSet<usize>, target: usize) -> Vec<usize> {
+        if learned_clauses.is_empty() {
+            return Vec::new();
+        }
+        let average_length = learned_clauses.iter().map(|clause| clause.literals_len()).sum::<usize>() / learned_clauses.len();
+        return (0..target.min(learned_clauses.len()))
+            .filter(|idx| !locked.contains(idx) && learned_clauses[*idx].literals_len() > average_length)
+            .collect();
+    }
+}
\ No newline at end of file
@@ -0,0 +1,126 @@
+use crate::consts::reduction;
+
+/// The schedule of the deletions of the learned clauses
+/// The learned clauses are reduced when they exceed a limit, then the limit grows by a factor.
+pub struct Schedule {
+    first_limit: Option<usize>,
+    growth: f64,
+    fraction: f64,
+
+    limit: usize,
+}
+
+impl Schedule {
+    pub fn new() -> Schedule {
+        Schedule {
+            first_limit: None,
+            growth: reduction::DEFAULT_GROWTH,
+            fraction: reduction::DEFAULT_FRACTION,
+
+            limit: 0,
+        }
+    }
+
+    /// Sets the limit to the first limit, before any learned clause is added
+    /// 
+    /// # Arguments
+    /// 
+    /// * `num_clauses` - The number of clauses of the formula, the first limit when it is not set
+    /// 
+    pub fn start(&mut self, num_clauses: usize) {
+        self.limit = self.first_limit.unwrap_or(num_clauses);
+    }
+
+    /// Returns the number of learned clauses of the first reduction
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<usize>` - The number of learned clauses, None if it is the number of clauses of the formula
+    /// 
+    pub fn get_first_limit(&self) -> Option<usize> {
+        self.first_limit
+    }
+
+    /// Sets the number of learned clauses of the first reduction
+    /// 
+    /// # Arguments
+    /// 
+    /// * `first_limit` - The number of learned clauses, None to use the number of clauses of the formula
+    /// 
+    pub fn set_first_limit(&mut self, first_limit: Option<usize>) {
+        self.first_limit = first_limit;
+    }
+
+    /// Returns the growth factor of the limit after every reduction
+    /// 
+    /// # Returns
+    /// 
+    /// * `f64` - The growth factor
+    /// 
+    pub fn get_growth(&self) -> f64 {
+        self.growth
+    }
+
+    /// Sets the growth factor of the limit after every reduction
+    /// 
+    /// # Arguments
+    /// 
+    /// * `growth` - The growth factor, at least 1
+    /// 
+    pub fn set_growth(&mut self, growth: f64) {
+        self.growth = growth.max(1.0);
+    }
+
+    /// Returns the fraction of the learned clauses to delete at every reduction
+    /// 
+    /// # Returns
+    /// 
+    /// * `f64` - The fraction
+    /// 
+    pub fn get_fraction(&self) -> f64 {
+        self.fraction
+    }
+
+    /// Sets the fraction of the learned clauses to delete at every reduction
+    /// 
+    /// # Arguments
+    /// 
+    /// * `fraction` - The fraction, between 0 and 1
+    /// 
+    pub fn set_fraction(&mut self, fraction: f64) {
+        self.fraction = fraction.clamp(0.0, 1.0);
+    }
+
+    /// Checks if a reduction is due
+    /// 
+    /// # Arguments
+    /// 
+    /// * `learned_clauses` - The number of learned clauses
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - true if the learned clauses exceed the limit, false otherwise
+    /// 
+    pub fn is_due(&self, learned_clauses: usize) -> bool {
+        learned_clauses > self.limit
+    }
+
+    /// Returns the number of clauses to delete
+    /// 
+    /// # Arguments
+    /// 
+    /// * `learned_clauses` - The number of learned clauses
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of clauses to delete
+    /// 
+    pub fn get_target(&self, learned_clauses: usize) -> usize {
+        (learned_clauses as f64 * self.fraction) as usize
+    }
+
+    /// Grows the limit after a reduction
+    pub fn next(&mut self) {
+        self.limit = ((self.limit as f64 * self.growth).round() as usize).max(self.limit + 1);
+    }
+}
\ No newline at end of file
@@ -1,9 +1,23 @@
+mod dpll;
+mod davis_putnam;
+mod portfolio;
+mod cube_and_conquer;
+mod fragment;
+mod xor;
+mod optimization;
+
 use std::vec;
+use std::time::{Duration, Instant};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use chrono::Utc;
+use rand::{rngs::StdRng, Rng, SeedableRng};
 
 use crate::tools::clause_tools;
-use crate::consts::{sat::SAT, operators};
-use crate::classes::{clause::Clause, formula::Formula, decision::Decision, file::File, model::Model, stats::Stats};
+use crate::consts::{sat::SAT, encoding::{self, Encoding}, engine::Engine, fragment::Fragment, operators, optimization::Optimization, phase::Phase, preprocessing, reduction::ACTIVITY_DECAY};
+use crate::classes::heuristic::{self, DecisionHeuristic, vsids::Vsids};
+use crate::classes::reduction::{self, ReductionPolicy, length::Length, schedule::Schedule};
+use crate::classes::{clause::Clause, cuber::Cuber, formula::Formula, decision::Decision, file::File, gauss::Gauss, limits::Limits, local_search::LocalSearch, model::Model, progress::Progress, preprocessor::Preprocessor, stats::Stats};
 
 
 pub struct Solver {
@@ -11,15 +25,40 @@ pub struct Solver {
 
     learned_clauses: Vec<Clause>,
     current_learned_clause_id: usize,
-    max_learned_clauses: usize,
+    reduction: Box<dyn ReductionPolicy>,
+    reduction_schedule: Schedule,
 
     model: Model,
 
+    engine: Engine,
+    local_search: LocalSearch,
+    cuber: Cuber,
+    fragment_solvers: bool,
+    gauss: Gauss,
+    xor_reasons: HashSet<Vec<isize>>,
+    xor_detection: bool,
+    optimization: Optimization,
+    encoding: Encoding,
     decision_level: usize,
     decisions: Vec<Decision>,
-    vsids: Vec<(f32, f32)>,
+    assumptions: Vec<isize>,
+    heuristic: Box<dyn DecisionHeuristic>,
+    random_decision_frequency: f64,
+    rng: StdRng,
+    minimization: bool,
 
     stats: Stats,
+    limits: Limits,
+    progress: Progress,
+
+    preprocessing: bool,
+    preprocessor: Preprocessor,
+    next_inprocessing: usize,
+
+    portfolio_workers: usize,
+    exchange: Option<(Arc<portfolio::ClauseExchange>, usize)>,
+    exchange_cursor: usize,
+    workers: Vec<portfolio::Worker>,
 
     print_dot_proof: bool,
     file_dot: File,
@@ -38,15 +77,40 @@ impl Solver {
 
             learned_clauses: Vec::new(),
             current_learned_clause_id: 0,
-            max_learned_clauses: 0,
+            reduction: Box::new(Length {}),
+            reduction_schedule: Schedule::new(),
 
             model: Model::new(None),
 
+            engine: Engine::Cdcl,
+            local_search: LocalSearch::new(),
+            cuber: Cuber::new(),
+            fragment_solvers: true,
+            gauss: Gauss::new(),
+            xor_reasons: HashSet::new(),
+            xor_detection: true,
+            optimization: Optimization::Linear,
+            encoding: encoding::DEFAULT_ENCODING,
             decision_level: 0,
             decisions: Vec::new(),
-            vsids: Vec::new(),
+            assumptions: Vec::new(),
+            heuristic: Box::new(Vsids::new()),
+            random_decision_frequency: 0.0,
+            rng: StdRng::seed_from_u64(0),
+            minimization: false,
 
             stats: Stats::new(),
+            limits: Limits::new(),
+            progress: Progress::new(),
+
+            preprocessing: false,
+            preprocessor: Preprocessor::new(),
+            next_inprocessing: 0,
+
+            portfolio_workers: 1,
+            exchange: None,
+            exchange_cursor: 0,
+            workers: Vec::new(),
 
             print_dot_proof: false,
             file_dot: File::new(None),
@@ -70,11 +134,16 @@ impl Solver {
     pub fn reset_solve(&mut self) {
         self.model = Model::new(None);
         self.learned_clauses = Vec::new();
+        self.xor_reasons = HashSet::new();
         self.decision_level = 0;
         self.decisions = Vec::new();
-        self.vsids = Vec::new();
+        self.assumptions = Vec::new();
+        self.heuristic.reset();
         self.stats = Stats::new();
-        self.max_learned_clauses = 0;
+        self.limits.reset();
+        self.progress.reset();
+        self.reduction_schedule.start(0);
+        self.workers = Vec::new();
 
         self.formula.get_mut_clauses().iter_mut().for_each(|clause| {
             clause.reset_satisfied(self.decision_level);
@@ -88,7 +157,7 @@ impl Solver {
     /// * `bool` - True if the formula is loaded, false otherwise.
     /// 
     pub fn is_formula_loaded(&self) -> bool {
-        return self.formula.get_num_clauses() != 0;
+        return self.formula.get_num_clauses() != 0 || !self.formula.get_xors().is_empty();
     }
 
     /// Add a clause to the formula as a learned clause.
@@ -207,24 +276,816 @@ impl Solver {
         self.print_tex_proof = enable;
     }
 
+    /// Check if the learned clause minimization is enabled.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the learned clause minimization is enabled, false otherwise.
+    /// 
+    pub fn is_minimization_enabled(&self) -> bool {
+        return self.minimization;
+    }
+
+    /// Set the learned clause minimization to be enabled or disabled.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `enable` - The value to set the learned clause minimization to.
+    /// 
+    pub fn set_minimization_enabled(&mut self, enable: bool) {
+        self.minimization = enable;
+    }
+
+    /// Returns the name of the decision heuristic.
+    /// 
+    /// # Returns
+    /// 
+    /// * `&'static str` - The name of the heuristic.
+    /// 
+    pub fn get_heuristic(&self) -> &'static str {
+        return self.heuristic.name();
+    }
+
+    /// Set the decision heuristic, the scores of the previous heuristic are lost.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `name` - The name of the heuristic, one of `consts::heuristics::HEURISTICS` ignoring the case.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<(), ()>` - An error if the name is unknown.
+    /// 
+    pub fn set_heuristic(&mut self, name: &str) -> Result<(), ()> {
+        match heuristic::from_name(name) {
+            Some(heuristic) => {
+                self.heuristic = heuristic;
+                return Ok(());
+            },
+            None => return Err(()),
+        }
+    }
+
+    /// Returns the engine used by `solve`.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Engine` - The engine.
+    /// 
+    pub fn get_engine(&self) -> Engine {
+        return self.engine;
+    }
+
+    /// Set the engine used by `solve`, the incremental calls with assumptions always use CDCL.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `engine` - The engine.
+    /// 
+    pub fn set_engine(&mut self, engine: Engine) {
+        self.engine = engine;
+    }
+
+    /// Returns the number of workers of the portfolio.
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of workers, 1 if the portfolio is disabled.
+    /// 
+    pub fn get_portfolio_workers(&self) -> usize {
+        return self.portfolio_workers;
+    }
+
+    /// Set the number of workers of the portfolio, each one runs a CDCL solver on a separate thread.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `workers` - The number of workers, 1 to disable the portfolio.
+    /// 
+    pub fn set_portfolio_workers(&mut self, workers: usize) {
+        self.portfolio_workers = workers.max(1);
+    }
+
+    /// Set the frequency of the decisions on a random unassigned literal instead of the heuristic.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `frequency` - The probability of a random decision, 0 to disable them.
+    /// * `seed` - The seed of the random generator.
+    /// 
+    pub fn set_random_decisions(&mut self, frequency: f64, seed: u64) {
+        self.random_decision_frequency = frequency.clamp(0.0, 1.0);
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    /// Returns the probability of a random walk step of WalkSAT.
+    /// 
+    /// # Returns
+    /// 
+    /// * `f64` - The noise.
+    /// 
+    pub fn get_local_search_noise(&self) -> f64 {
+        return self.local_search.get_noise();
+    }
+
+    /// Set the probability of a random walk step of WalkSAT.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `noise` - The noise, between 0 and 1.
+    /// 
+    pub fn set_local_search_noise(&mut self, noise: f64) {
+        self.local_search.set_noise(noise);
+    }
+
+    /// Returns the cb parameter of ProbSAT.
+    /// 
+    /// # Returns
+    /// 
+    /// * `f64` - The cb parameter.
+    /// 
+    pub fn get_local_search_cb(&self) -> f64 {
+        return self.local_search.get_cb();
+    }
+
+    /// Set the cb parameter of ProbSAT, higher values prefer the variables that falsify fewer clauses.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `cb` - The cb parameter.
+    /// 
+    pub fn set_local_search_cb(&mut self, cb: f64) {
+        self.local_search.set_cb(cb);
+    }
+
+    /// Returns the maximum number of flips of a try of the local search.
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The flip limit.
+    /// 
+    pub fn get_local_search_max_flips(&self) -> usize {
+        return self.local_search.get_max_flips();
+    }
+
+    /// Set the maximum number of flips of a try of the local search.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `max_flips` - The flip limit.
+    /// 
+    pub fn set_local_search_max_flips(&mut self, max_flips: usize) {
+        self.local_search.set_max_flips(max_flips);
+    }
+
+    /// Returns the maximum number of restarts of the local search.
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The restart limit.
+    /// 
+    pub fn get_local_search_max_restarts(&self) -> usize {
+        return self.local_search.get_max_restarts();
+    }
+
+    /// Set the maximum number of restarts of the local search.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `max_restarts` - The restart limit.
+    /// 
+    pub fn set_local_search_max_restarts(&mut self, max_restarts: usize) {
+        self.local_search.set_max_restarts(max_restarts);
+    }
+
+    /// Set the seed of the random generator of the local search.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `seed` - The seed.
+    /// 
+    pub fn set_local_search_seed(&mut self, seed: u64) {
+        self.local_search.set_seed(seed);
+    }
+
+    /// Check if the 2-CNF, Horn and dual-Horn formulas are solved by the linear-time solvers.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the linear-time solvers are enabled, false otherwise.
+    /// 
+    pub fn is_fragment_solvers_enabled(&self) -> bool {
+        return self.fragment_solvers;
+    }
+
+    /// Enable or disable the linear-time solvers of the 2-CNF, Horn and dual-Horn formulas.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `enable` - True to solve these formulas with the linear-time solvers, false to solve them with the CDCL engine.
+    /// 
+    pub fn set_fragment_solvers_enabled(&mut self, enable: bool) {
+        self.fragment_solvers = enable;
+    }
+
+    /// Check if the XOR constraints encoded as clauses are detected and propagated by the Gauss-Jordan elimination.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the detection is enabled, false otherwise.
+    /// 
+    pub fn is_xor_detection_enabled(&self) -> bool {
+        return self.xor_detection;
+    }
+
+    /// Enable or disable the detection of the XOR constraints encoded as clauses.
+    /// The XOR constraints of the formula are always propagated by the Gauss-Jordan elimination.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `enable` - True to detect the XOR constraints encoded as clauses, false otherwise.
+    /// 
+    pub fn set_xor_detection_enabled(&mut self, enable: bool) {
+        self.xor_detection = enable;
+    }
+
+    /// Returns the maximum number of decisions of a cube of cube-and-conquer.
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The depth of the split.
+    /// 
+    pub fn get_cube_depth(&self) -> usize {
+        return self.cuber.get_depth();
+    }
+
+    /// Set the maximum number of decisions of a cube of cube-and-conquer.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `depth` - The depth of the split, the formula is split in at most 2^depth cubes.
+    /// 
+    pub fn set_cube_depth(&mut self, depth: usize) {
+        self.cuber.set_depth(depth);
+    }
+
+    /// Returns the search of the optimum of the objective function.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Optimization` - The search.
+    /// 
+    pub fn get_optimization(&self) -> Optimization {
+        return self.optimization;
+    }
+
+    /// Set the search of the optimum of the objective function.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `optimization` - The search.
+    /// 
+    pub fn set_optimization(&mut self, optimization: Optimization) {
+        self.optimization = optimization;
+    }
+
+    /// Returns the encoding of the pseudo-Boolean constraints of the optimization.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Encoding` - The encoding.
+    /// 
+    pub fn get_encoding(&self) -> Encoding {
+        return self.encoding;
+    }
+
+    /// Set the encoding of the pseudo-Boolean constraints of the optimization, the bounds of the objective function with
+    /// different weights are encoded with the fallback encoding if the encoding only handles cardinality constraints.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `encoding` - The encoding.
+    /// 
+    pub fn set_encoding(&mut self, encoding: Encoding) {
+        self.encoding = encoding;
+    }
+
+    /// Returns the name of the deletion policy of the learned clauses.
+    /// 
+    /// # Returns
+    /// 
+    /// * `&'static str` - The name of the policy.
+    /// 
+    pub fn get_reduction_policy(&self) -> &'static str {
+        return self.reduction.name();
+    }
+
+    /// Set the deletion policy of the learned clauses.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `name` - The name of the policy, one of `consts::reduction::POLICIES` ignoring the case.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<(), ()>` - An error if the name is unknown.
+    /// 
+    pub fn set_reduction_policy(&mut self, name: &str) -> Result<(), ()> {
+        match reduction::from_name(name) {
+            Some(policy) => {
+                self.reduction = policy;
+                return Ok(());
+            },
+            None => return Err(()),
+        }
+    }
+
+    /// Returns the number of learned clauses of the first reduction.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<usize>` - The number of learned clauses, None if it is the number of clauses of the formula.
+    /// 
+    pub fn get_reduction_first_limit(&self) -> Option<usize> {
+        return self.reduction_schedule.get_first_limit();
+    }
+
+    /// Set the number of learned clauses of the first reduction.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `first_limit` - The number of learned clauses, None to use the number of clauses of the formula.
+    /// 
+    pub fn set_reduction_first_limit(&mut self, first_limit: Option<usize>) {
+        self.reduction_schedule.set_first_limit(first_limit);
+    }
+
+    /// Returns the growth factor of the limit of the learned clauses after every reduction.
+    /// 
+    /// # Returns
+    /// 
+    /// * `f64` - The growth factor.
+    /// 
+    pub fn get_reduction_growth(&self) -> f64 {
+        return self.reduction_schedule.get_growth();
+    }
+
+    /// Set the growth factor of the limit of the learned clauses after every reduction.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `growth` - The growth factor, at least 1.
+    /// 
+    pub fn set_reduction_growth(&mut self, growth: f64) {
+        self.reduction_schedule.set_growth(growth);
+    }
+
+    /// Returns the fraction of the learned clauses to delete at every reduction.
+    /// 
+    /// # Returns
+    /// 
+    /// * `f64` - The fraction.
+    /// 
+    pub fn get_reduction_fraction(&self) -> f64 {
+        return self.reduction_schedule.get_fraction();
+    }
+
+    /// Set the fraction of the learned clauses to delete at every reduction.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `fraction` - The fraction, between 0 and 1.
+    /// 
+    pub fn set_reduction_fraction(&mut self, fraction: f64) {
+        self.reduction_schedule.set_fraction(fraction);
+    }
+
+    /// Returns the wall-clock timeout of the solver.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<Duration>` - The timeout, None if there is no timeout.
+    /// 
+    pub fn get_timeout(&self) -> Option<Duration> {
+        return self.limits.get_timeout();
+    }
+
+    /// Set the wall-clock timeout of the solver.
+    /// When it expires the solver stops with an unknown result.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `timeout` - The timeout, None to remove it.
+    /// 
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.limits.set_timeout(timeout);
+    }
+
+    /// Returns the maximum number of conflicts of the solver.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<usize>` - The maximum number of conflicts, None if there is no limit.
+    /// 
+    pub fn get_conflict_limit(&self) -> Option<usize> {
+        return self.limits.get_max_conflicts();
+    }
+
+    /// Set the maximum number of conflicts of the solver.
+    /// When it is reached the solver stops with an unknown result.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `max_conflicts` - The maximum number of conflicts, None to remove the limit.
+    /// 
+    pub fn set_conflict_limit(&mut self, max_conflicts: Option<usize>) {
+        self.limits.set_max_conflicts(max_conflicts);
+    }
+
+    /// Returns the maximum number of decisions of the solver.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<usize>` - The maximum number of decisions, None if there is no limit.
+    /// 
+    pub fn get_decision_limit(&self) -> Option<usize> {
+        return self.limits.get_max_decisions();
+    }
+
+    /// Set the maximum number of decisions of the solver.
+    /// When it is reached the solver stops with an unknown result.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `max_decisions` - The maximum number of decisions, None to remove the limit.
+    /// 
+    pub fn set_decision_limit(&mut self, max_decisions: Option<usize>) {
+        self.limits.set_max_decisions(max_decisions);
+    }
+
+    /// Returns the maximum physical memory of the solver, in megabytes.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<usize>` - The maximum physical memory, None if there is no limit.
+    /// 
+    pub fn get_memory_limit(&self) -> Option<usize> {
+        return self.limits.get_max_memory();
+    }
+
+    /// Set the maximum physical memory of the solver, in megabytes.
+    /// When it is exceeded the solver stops with an unknown result.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `max_memory` - The maximum physical memory, None to remove the limit.
+    /// 
+    pub fn set_memory_limit(&mut self, max_memory: Option<usize>) {
+        self.limits.set_max_memory(max_memory);
+    }
+
+    /// Returns the number of conflicts between two progress reports.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<usize>` - The number of conflicts, None if the reports are not based on conflicts.
+    /// 
+    pub fn get_progress_conflict_interval(&self) -> Option<usize> {
+        return self.progress.get_conflict_interval();
+    }
+
+    /// Set the number of conflicts between two progress reports.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `conflict_interval` - The number of conflicts, None to disable the reports based on conflicts.
+    /// 
+    pub fn set_progress_conflict_interval(&mut self, conflict_interval: Option<usize>) {
+        self.progress.set_conflict_interval(conflict_interval);
+    }
+
+    /// Returns the time between two progress reports.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<Duration>` - The time, None if the reports are not based on time.
+    /// 
+    pub fn get_progress_time_interval(&self) -> Option<Duration> {
+        return self.progress.get_time_interval();
+    }
+
+    /// Set the time between two progress reports.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `time_interval` - The time, None to disable the reports based on time.
+    /// 
+    pub fn set_progress_time_interval(&mut self, time_interval: Option<Duration>) {
+        self.progress.set_time_interval(time_interval);
+    }
+
+    /// Set the progress reports to be a live panel, used by the interactive mode, instead of comment lines.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `live` - True for the live panel, false for the comment lines.
+    /// 
+    pub fn set_progress_live(&mut self, live: bool) {
+        self.progress.set_live(live);
+    }
+
+    /// Check if the preprocessing is enabled.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the preprocessing is enabled, false otherwise.
+    /// 
+    pub fn is_preprocessing_enabled(&self) -> bool {
+        return self.preprocessing;
+    }
+
+    /// Set the preprocessing to be enabled or disabled.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `enable` - The value to set the preprocessing to.
+    /// 
+    pub fn set_preprocessing_enabled(&mut self, enable: bool) {
+        self.preprocessing = enable;
+    }
+
+    /// Check if the bounded variable elimination is enabled.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the bounded variable elimination is enabled, false otherwise.
+    /// 
+    pub fn is_variable_elimination_enabled(&self) -> bool {
+        return self.preprocessor.is_variable_elimination_enabled();
+    }
+
+    /// Set the bounded variable elimination to be enabled or disabled.
+    /// It is applied only if the preprocessing is enabled.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `enable` - The value to set the bounded variable elimination to.
+    /// 
+    pub fn set_variable_elimination_enabled(&mut self, enable: bool) {
+        self.preprocessor.set_variable_elimination_enabled(enable);
+    }
+
+    /// Check if the failed literal probing and the equivalent literal substitution are enabled.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the probing is enabled, false otherwise.
+    /// 
+    pub fn is_probing_enabled(&self) -> bool {
+        return self.preprocessor.is_probing_enabled();
+    }
+
+    /// Set the failed literal probing and the equivalent literal substitution to be enabled or disabled.
+    /// They are applied only if the preprocessing is enabled, both before and during the search.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `enable` - The value to set the probing to.
+    /// 
+    pub fn set_probing_enabled(&mut self, enable: bool) {
+        self.preprocessor.set_probing_enabled(enable);
+    }
+
+    /// Check if the blocked clause elimination is enabled.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the blocked clause elimination is enabled, false otherwise.
+    /// 
+    pub fn is_blocked_clause_elimination_enabled(&self) -> bool {
+        return self.preprocessor.is_blocked_clause_elimination_enabled();
+    }
+
+    /// Set the blocked clause elimination to be enabled or disabled.
+    /// It is applied only if the preprocessing is enabled.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `enable` - The value to set the blocked clause elimination to.
+    /// 
+    pub fn set_blocked_clause_elimination_enabled(&mut self, enable: bool) {
+        self.preprocessor.set_blocked_clause_elimination_enabled(enable);
+    }
+
+    /// Check if the covered clause elimination is enabled.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the covered clause elimination is enabled, false otherwise.
+    /// 
+    pub fn is_covered_clause_elimination_enabled(&self) -> bool {
+        return self.preprocessor.is_covered_clause_elimination_enabled();
+    }
+
+    /// Set the covered clause elimination to be enabled or disabled.
+    /// It is applied only if the preprocessing and the blocked clause elimination are enabled.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `enable` - The value to set the covered clause elimination to.
+    /// 
+    pub fn set_covered_clause_elimination_enabled(&mut self, enable: bool) {
+        self.preprocessor.set_covered_clause_elimination_enabled(enable);
+    }
+
     /// Main function to solve the formula.
     /// Returns the result of the formula if it is satisfiable, unsatisfiable or unknown.
+    /// The result is unknown if a limit of the solver is reached before the search ends.
     /// Returns an error if the formula is not loaded.
+    /// If the preprocessing is enabled, the simplified formula is solved and its model is extended to the original formula,
+    /// which is restored at the end. The resolution steps of the preprocessing are written in the proof files.
+    /// The DPLL and Davis-Putnam engines solve the original formula, without preprocessing.
+    /// The local search engines cannot prove that a formula is unsatisfiable, they return unknown if a model is not found.
+    /// The CDCL engine solves the 2-CNF, Horn and dual-Horn formulas with the linear-time solvers, if they are enabled.
+    /// With more than one portfolio worker, the CDCL engine runs the portfolio.
+    /// Cube-and-conquer splits the original formula and solves the cubes with the workers of the portfolio, without proofs.
     /// 
     pub fn solve(&mut self) -> Result<SAT, ()> {
 
+        self.limits.start();
+        self.progress.start();
+
+        if self.fragment_solvers && self.engine == Engine::Cdcl && self.is_formula_loaded() && self.formula.get_fragment() != Fragment::General {
+            return self.solve_fragment();
+        }
+        if self.portfolio_workers > 1 && self.engine == Engine::Cdcl && self.is_formula_loaded() {
+            return self.solve_portfolio();
+        }
+        if !self.formula.get_xors().is_empty() && matches!(self.engine, Engine::Dpll | Engine::DavisPutnam | Engine::WalkSat | Engine::ProbSat) {
+            return self.solve_encoded_xors();
+        }
+        match self.engine {
+            Engine::Dpll if self.is_formula_loaded() => return self.solve_dpll(),
+            Engine::DavisPutnam if self.is_formula_loaded() => return self.solve_davis_putnam(),
+            Engine::WalkSat | Engine::ProbSat if self.is_formula_loaded() => return self.solve_local_search(),
+            Engine::CubeAndConquer if self.is_formula_loaded() => return self.solve_cube_and_conquer(),
+            _ => (),
+        }
+
+        // The preprocessor does not know the XOR constraints, so it could eliminate their variables.
+        if !self.preprocessing || !self.is_formula_loaded() || !self.formula.get_xors().is_empty() {
+            return self.solve_with_assumptions(&Vec::new());
+        }
+
+        let original_formula = self.formula.clone();
+
+        self.file_init();
+
+        let preprocessing_start = Instant::now();
+        let preprocessed = self.preprocessor.run(&mut self.formula);
+        self.stats.add_time(Phase::Preprocessing, preprocessing_start.elapsed());
+        self.stats.increase_clauses_eliminated(self.preprocessor.get_clauses_eliminated());
+        self.stats.increase_variables_eliminated(self.preprocessor.get_variables_eliminated());
+        self.stats.update();
+        self.file_print_preprocessing("Preprocess");
+
+        let result = match preprocessed {
+            SAT::Unknown => self.search(&Vec::new(), self.preprocessor.is_probing_enabled()),
+            SAT::Satisfiable => {
+                self.model = Model::new(Some(self.formula.get_num_variables()));
+                self.file_delete();
+                Ok(SAT::Satisfiable)
+            },
+            SAT::Unsatisfiable => {
+                self.file_close();
+                Ok(SAT::Unsatisfiable)
+            },
+        };
+
+        if result == Ok(SAT::Satisfiable) {
+            self.preprocessor.extend_model(&mut self.model);
+        }
+
+        self.formula = original_formula;
+
+        return result;
+    }
+
+    /// Search a model with the local search engine, no proof is written.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<SAT, ()>` - Satisfiable if a model is found, unknown otherwise.
+    /// 
+    fn solve_local_search(&mut self) -> Result<SAT, ()> {
+
+        let search_start = Instant::now();
+        let model = match self.engine {
+            Engine::ProbSat => self.local_search.probsat(&self.formula, &self.limits, &mut self.stats),
+            _ => self.local_search.walksat(&self.formula, &self.limits, &mut self.stats),
+        };
+        self.stats.add_time(Phase::Search, search_start.elapsed());
+        self.stats.update();
+
+        match model {
+            Some(model) => {
+                self.model = model;
+                return Ok(SAT::Satisfiable);
+            },
+            None => {
+                self.model = Model::new(Some(self.formula.get_num_variables()));
+                return Ok(SAT::Unknown);
+            },
+        }
+    }
+
+    /// Solve the formula under a set of assumptions.
+    /// The assumptions are decided before any other literal, if one of them is falsified the formula is unsatisfiable under the assumptions.
+    /// The learned clauses of previous calls are kept, so the solver can be called incrementally.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `assumptions` - The literals assumed to be true.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<SAT, ()>` - The result of the formula under the assumptions, an error if the formula is not loaded or an assumption is not a variable of the formula.
+    /// 
+    pub fn solve_with_assumptions(&mut self, assumptions: &Vec<isize>) -> Result<SAT, ()> {
+
         if !self.is_formula_loaded() {
             return Err(());
         }
+        if assumptions.iter().any(|&literal| literal == 0 || literal.abs() as usize > self.formula.get_num_variables()) {
+            return Err(());
+        }
+
+        self.limits.start();
+        self.progress.start();
+        self.file_init();
+
+        return self.search(assumptions, false);
+    }
+
+    /// Search function, the main loop of the solver.
+    /// The proof files must be already initialized.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `assumptions` - The literals assumed to be true.
+    /// * `inprocessing` - True if the formula can be simplified during the search, it must be restored by the caller.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<SAT, ()>` - The result of the formula under the assumptions.
+    /// 
+    fn search(&mut self, assumptions: &Vec<isize>, inprocessing: bool) -> Result<SAT, ()> {
+
+        let search_start = Instant::now();
+        let result = self.search_loop(assumptions, inprocessing);
+        self.stats.add_time(Phase::Search, search_start.elapsed());
+        self.stats.update();
+
+        return result;
+    }
+
+    /// Main loop of the search, it starts from decision level 0.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `assumptions` - The literals assumed to be true.
+    /// * `inprocessing` - True if the formula can be simplified during the search.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<SAT, ()>` - The result of the formula under the assumptions.
+    /// 
+    fn search_loop(&mut self, assumptions: &Vec<isize>, inprocessing: bool) -> Result<SAT, ()> {
 
         self.decision_level = 0;
         self.decisions = vec![Decision::new(0)];
-        self.model.resize(self.formula.get_num_variables());
-        self.vsids = vec![(0.0, 0.0); self.formula.get_num_variables()];
-        self.current_learned_clause_id = self.formula.get_num_clauses();
-        self.max_learned_clauses = self.formula.get_num_clauses();
+        self.assumptions = assumptions.clone();
+        self.model = Model::new(Some(self.formula.get_num_variables()));
+        self.heuristic.resize(self.formula.get_num_variables());
+        let mut xors = self.formula.get_xors().clone();
+        if self.xor_detection {
+            xors.extend(self.formula.detect_xors());
+        }
+        self.gauss.load(xors);
+        // The inprocessing does not know the XOR constraints either.
+        let inprocessing = inprocessing && self.gauss.is_empty();
+        if self.learned_clauses.len() == 0 {
+            self.current_learned_clause_id = self.formula.get_current_clause_id();
+            self.reduction_schedule.start(self.formula.get_num_clauses());
+        }
 
-        self.file_init();
+        self.formula.get_mut_clauses().iter_mut().for_each(|clause| {
+            clause.reset_satisfied(0);
+        });
+        self.learned_clauses.iter_mut().for_each(|clause| {
+            clause.reset_satisfied(0);
+        });
+        self.next_inprocessing = self.stats.get_clauses_learned() + preprocessing::INPROCESSING_INTERVAL;
 
         // Main loop to solve the formula.
         'solve_loop: loop {
@@ -233,21 +1094,48 @@ impl Solver {
             'unit_clause_loop: loop {
                 let mut model_changed = false;
 
+                if self.progress.is_due(&self.stats) {
+                    let learned_literals: usize = self.learned_clauses.iter().map(|clause| clause.literals_len()).sum();
+                    let average_length = learned_literals as f64 / self.learned_clauses.len().max(1) as f64;
+                    self.progress.report(&self.stats, self.learned_clauses.len(), average_length);
+                }
+
+                if let Some(limit) = self.limits.check(&self.stats) {
+                    self.stats.set_limit_reached(limit);
+                    self.file_close();
+                    return Ok(SAT::Unknown);
+                }
+
+                // Inprocessing at decision level 0.
+                if inprocessing && self.decision_level == 0 && self.stats.get_clauses_learned() >= self.next_inprocessing {
+                    self.next_inprocessing = self.stats.get_clauses_learned() + preprocessing::INPROCESSING_INTERVAL;
+                    match self.inprocess() {
+                        SAT::Satisfiable => {
+                            self.file_delete();
+                            return Ok(SAT::Satisfiable)
+                        },
+                        SAT::Unsatisfiable => {
+                            self.file_close();
+                            return Ok(SAT::Unsatisfiable);
+                        },
+                        SAT::Unknown => (),
+                    }
+                }
+
                 // Learned clauses loop.
                 'learned_clauses_loop: loop {
                     match clause_tools::get_next_unit_clause_literal(&mut self.learned_clauses, &self.model) {
                         Some((literal, clause_idx)) => {
                             let clause_idx = clause_idx + self.formula.get_num_clauses();
                             self.model.add(literal);
+                            self.heuristic.on_assign(literal);
                             self.decisions[self.decision_level].add_propagated_literal(literal, clause_idx);
+                            self.stats.increase_propagations();
                             self.tex_print_model("Propagation", None);
                             model_changed = true;
                             let (satisfied, conflict_clause_idx) = self.check_if_satisfied();
                             match satisfied {
-                                SAT::Satisfiable => {
-                                    self.file_delete();
-                                    return Ok(SAT::Satisfiable)
-                                },
+                                SAT::Satisfiable => return Ok(self.satisfied_under_assumptions()),
                                 SAT::Unsatisfiable => {
                                     let solved = self.conflict_solver(literal, clause_idx, conflict_clause_idx);
                                     if !solved {
@@ -270,15 +1158,14 @@ impl Solver {
                     match clause_tools::get_next_unit_clause_literal(self.formula.get_clauses(), &self.model) {
                         Some((literal, clause_idx)) => {
                             self.model.add(literal);
+                            self.heuristic.on_assign(literal);
                             self.decisions[self.decision_level].add_propagated_literal(literal, clause_idx);
+                            self.stats.increase_propagations();
                             self.tex_print_model("Propagation", None);
                             model_changed = true;
                             let (satisfied, conflict_clause_idx) = self.check_if_satisfied();
                             match satisfied {
-                                SAT::Satisfiable => {
-                                    self.file_delete();
-                                    return Ok(SAT::Satisfiable)
-                                },
+                                SAT::Satisfiable => return Ok(self.satisfied_under_assumptions()),
                                 SAT::Unsatisfiable => {
                                     let solved = self.conflict_solver(literal, clause_idx, conflict_clause_idx);
                                     if !solved {
@@ -300,9 +1187,52 @@ impl Solver {
                 }
             }
 
-            let decided_literal = self.decision();
+            // Clauses learned by the other workers of the portfolio.
+            match self.import_shared_clauses() {
+                Ok(true) => continue 'solve_loop,
+                Ok(false) => (),
+                Err(()) => {
+                    self.file_close();
+                    return Ok(SAT::Unsatisfiable);
+                },
+            }
+
+            // Implications and conflicts of the XOR constraints.
+            match self.propagate_xors() {
+                Ok(true) => continue 'solve_loop,
+                Ok(false) => (),
+                Err(()) => {
+                    self.file_close();
+                    return Ok(SAT::Unsatisfiable);
+                },
+            }
+
+            let decision_start = Instant::now();
+            let decided_literal = match self.next_assumption() {
+                Ok(Some(assumption)) => Some(assumption),
+                Ok(None) => match self.random_decision() {
+                    Some(literal) => Some(literal),
+                    None => self.heuristic.decide(&self.model, self.formula.get_clauses(), &self.learned_clauses)
+                        .or_else(|| self.gauss.get_unassigned_variable(&self.model).map(|variable| -(variable as isize))),
+                },
+                Err(()) => {
+                    self.file_close();
+                    return Ok(SAT::Unsatisfiable);
+                },
+            };
+            self.stats.add_time(Phase::Decision, decision_start.elapsed());
+            // Every variable is assigned without a conflict, so the model satisfies the formula.
+            let decided_literal = match decided_literal {
+                Some(literal) => literal,
+                None => {
+                    self.file_delete();
+                    return Ok(SAT::Satisfiable);
+                },
+            };
             self.model.add(decided_literal);
+            self.heuristic.on_assign(decided_literal);
             self.decision_level += 1;
+            self.stats.increase_decisions();
             self.decisions.push(Decision::new(decided_literal));
 
             self.tex_print_model("Decision", None);
@@ -311,16 +1241,36 @@ impl Solver {
 
             let (satisfied, _) = self.check_if_satisfied();
             match satisfied {
-                SAT::Satisfiable => {
-                    self.file_delete();
-                    return Ok(SAT::Satisfiable)
-                },
+                SAT::Satisfiable => return Ok(self.satisfied_under_assumptions()),
                 _ => continue 'solve_loop,
             }
 
         }
     }
 
+    /// Ends the search when every clause is satisfied, the assumptions are checked because the clauses can be satisfied
+    /// before every assumption is decided. An unassigned assumption is added to the model, a falsified one makes the
+    /// formula unsatisfiable under the assumptions.
+    /// 
+    /// # Returns
+    /// 
+    /// * `SAT` - Satisfiable if every assumption holds in the model, unsatisfiable otherwise.
+    /// 
+    fn satisfied_under_assumptions(&mut self) -> SAT {
+        for assumption in self.assumptions.clone() {
+            match self.model.satisfies(assumption) {
+                SAT::Satisfiable => (),
+                SAT::Unknown => self.model.add(assumption),
+                SAT::Unsatisfiable => {
+                    self.file_close();
+                    return SAT::Unsatisfiable;
+                },
+            }
+        }
+        self.file_delete();
+        return SAT::Satisfiable;
+    }
+
     /// Check if the formula is satisfiable.
     /// 
     /// # Returns
@@ -347,12 +1297,82 @@ impl Solver {
         if satisfied2 == SAT::Unsatisfiable {
             return (satisfied2, conflict_clause2);
         }
+        // The clauses can be satisfied before every variable of the XOR constraints is assigned.
+        if satisfied + satisfied2 == SAT::Satisfiable && !self.gauss.is_satisfied(&self.model) {
+            return (SAT::Unknown, 0);
+        }
         return (satisfied + satisfied2, 0)
     }
 
-    /// Print the model of the formula.
+    /// Check if the model satisfies every clause of the formula.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if every clause has a true literal in the model and every XOR constraint is true, false otherwise.
+    /// 
+    pub fn verify_model(&self) -> bool {
+        return self.formula.get_clauses().iter().all(|clause| {
+            clause.iter_literals().any(|&literal| self.model.has(literal))
+        }) && self.formula.get_xors().iter().all(|xor| xor.is_satisfied_by_model(&self.model) == SAT::Satisfiable);
+    }
+
+    /// Returns the statistics of the solver.
+    /// 
+    /// # Returns
+    /// 
+    /// * `&Stats` - The statistics of the last search.
+    /// 
+    pub fn get_stats(&self) -> &Stats {
+        return &self.stats;
+    }
+
+    /// Print the model of the formula, without the auxiliary variables of the encodings.
     pub fn print_model(&self) {
-        self.model.print();
+        self.model.print(|variable| self.formula.is_auxiliary_variable(variable));
+    }
+
+    /// Compute the backbone of the formula, the literals that are true in every model.
+    /// Starting from a first model, every literal of the model is a candidate: it is tested by solving under the assumption of its negation.
+    /// If the formula is still satisfiable, the candidates not in the new model are discarded, otherwise the literal is in the backbone.
+    /// The learned clauses are kept between the checks, and every backbone literal is learned as a unit clause.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<(SAT, Vec<isize>), ()>` - The result of the formula and the backbone literals (empty if the formula is not satisfiable), an error if the formula is not loaded.
+    /// 
+    pub fn compute_backbone(&mut self) -> Result<(SAT, Vec<isize>), ()> {
+
+        let sat = self.solve()?;
+        if sat != SAT::Satisfiable {
+            return Ok((sat, Vec::new()));
+        }
+
+        let mut candidates = self.model.get_literals();
+        let mut backbone: Vec<isize> = Vec::new();
+
+        while let Some(candidate) = candidates.pop() {
+            match self.solve_with_assumptions(&vec![-candidate])? {
+                SAT::Satisfiable => {
+                    candidates.retain(|&literal| self.model.has(literal));
+                },
+                SAT::Unsatisfiable => {
+                    backbone.push(candidate);
+                    let mut unit_clause = Clause::new();
+                    unit_clause.load_vec(vec![candidate]);
+                    self.current_learned_clause_id += 1;
+                    unit_clause.set_id(self.current_learned_clause_id);
+                    self.add_learned_clause(unit_clause);
+                },
+                SAT::Unknown => {
+                    return Ok((SAT::Unknown, Vec::new()));
+                },
+            }
+        }
+
+        backbone.sort_by(|a, b| a.abs().cmp(&b.abs()));
+        self.stats.set_backbone(backbone.len(), self.formula.get_num_variables());
+
+        return Ok((SAT::Satisfiable, backbone));
     }
 
     /// Conflict solver function.
@@ -369,29 +1389,55 @@ impl Solver {
     /// 
     fn conflict_solver(&mut self, conflict_literal: isize, clause_idx: usize, conflict_clause_idx: usize) -> bool {
 
-        self.update_vsids(conflict_clause_idx);
-        self.tex_print_model("Conflict", Some(format!("{}: {}", self.get_clause(conflict_clause_idx).get_id(), self.get_clause(conflict_clause_idx))));
+        let conflict_start = Instant::now();
+        self.stats.increase_conflicts();
+        self.learned_clauses.iter_mut().for_each(|clause| clause.set_activity(clause.get_activity() * ACTIVITY_DECAY));
+        let conflict_clause = self.get_clause(conflict_clause_idx).clone();
+        self.tex_print_model("Conflict", Some(format!("{}: {}", conflict_clause.get_id(), conflict_clause)));
+
+        let trail = self.get_trail();
 
-        let new_clause = self.explain(conflict_literal, clause_idx, conflict_clause_idx);
+        let mut new_clause = self.explain(conflict_literal, clause_idx, conflict_clause_idx);
 
         if new_clause.literals_len() == 0 {
+            self.stats.add_time(Phase::ConflictAnalysis, conflict_start.elapsed());
             return false;
         }
 
+        if self.minimization {
+            new_clause = self.minimize(new_clause, &trail);
+        }
+
+        self.heuristic.on_conflict(&conflict_clause, &new_clause);
+
+        let levels: HashSet<usize> = new_clause.iter_literals()
+            .filter_map(|literal| trail.get(&(literal.abs() as usize)).map(|&(level, _, _)| level))
+            .collect();
+        new_clause.set_lbd(levels.len());
+
         self.add_learned_clause(new_clause.clone());
+        self.export_learned_clause(&new_clause);
     
         self.tex_print_model("Learn", Some(format!("{}: {}", new_clause.get_id(), new_clause)));
 
         self.stats.increase_learned();
+        self.stats.add_learned_clause(new_clause.literals_len(), levels.len());
 
         self.stats.update();
 
-        if self.learned_clauses.len() > self.max_learned_clauses {
+        if self.reduction_schedule.is_due(self.learned_clauses.len()) {
             self.forget();
         }
 
+        let conflict_level = self.decision_level;
         self.backjump();
+        // The learned clause can still be falsified by the previous decision levels, it must be unit before the search goes on.
+        while self.decision_level > 0 && new_clause.iter_literals().all(|&literal| self.model.has(-literal)) {
+            self.backjump();
+        }
+        self.stats.add_backjump(conflict_level - self.decision_level);
 
+        self.stats.add_time(Phase::ConflictAnalysis, conflict_start.elapsed());
         
         return true;
 
@@ -476,6 +1522,8 @@ impl Solver {
 
         self.get_mut_clause(clause_idx).learned_clause_is_used_somewhere = true;
         self.get_mut_clause(conflict_clause_idx).learned_clause_is_used_somewhere = true;
+        self.bump_activity(clause_idx);
+        self.bump_activity(conflict_clause_idx);
 
         self.current_learned_clause_id = current_learned_clause_id;
 
@@ -483,10 +1531,190 @@ impl Solver {
 
     }
 
+    /// Returns the current trail, indexed by variable.
+    /// It must be built before the latest propagated literals are removed by the explain function.
+    /// A propagating clause is kept as reason only if all its other literals are falsified earlier in the trail.
+    /// 
+    /// # Returns
+    /// 
+    /// * `HashMap<usize, (usize, usize, Option<usize>)>` - For each assigned variable, its decision level, its position in the trail
+    /// and the index of the clause that propagated it, None if it was decided or its clause is not valid.
+    /// 
+    fn get_trail(&self) -> HashMap<usize, (usize, usize, Option<usize>)> {
+        let mut trail = HashMap::new();
+        let mut assigned_literals: HashSet<isize> = HashSet::new();
+        let num_clauses = self.formula.get_num_clauses() + self.learned_clauses.len();
+        let mut position = 0;
+        for (level, decision) in self.decisions.iter().enumerate() {
+            if level > 0 {
+                let decided_literal = decision.get_decided_literal();
+                trail.insert(decided_literal.abs() as usize, (level, position, None));
+                assigned_literals.insert(decided_literal);
+                position += 1;
+            }
+            for &(literal, clause_idx) in decision.get_propagated_literals().iter() {
+                let is_reason = clause_idx < num_clauses
+                    && self.get_clause(clause_idx).contains_literal(literal)
+                    && self.get_clause(clause_idx).iter_literals().all(|&other| other == literal || assigned_literals.contains(&-other));
+                trail.insert(literal.abs() as usize, (level, position, if is_reason { Some(clause_idx) } else { None }));
+                assigned_literals.insert(literal);
+                position += 1;
+            }
+        }
+        return trail;
+    }
+
+    /// Minimize function.
+    /// It removes from the learned clause the literals of the previous decision levels implied by the other literals
+    /// of the clause through their reason clauses, then it resolves them away so that every step is written in the proof.
+    /// The literals of the current decision level are kept, so the clause is not falsified by the backjump.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `learned_clause` - The clause returned by the explain function.
+    /// * `trail` - The trail before the explain function, as returned by `get_trail`.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Clause` - The minimized learned clause.
+    /// 
+    fn minimize(&mut self, learned_clause: Clause, trail: &HashMap<usize, (usize, usize, Option<usize>)>) -> Clause {
+
+        let clause_variables: HashSet<usize> = learned_clause.iter_literals().map(|literal| literal.abs() as usize).collect();
+        let mut redundant: HashMap<usize, bool> = HashMap::new();
+        let mut minimized_clause = learned_clause.clone();
+
+        for &literal in learned_clause.iter_literals() {
+            let removable = match trail.get(&(literal.abs() as usize)) {
+                Some(&(level, _, Some(_))) => level < self.decision_level,
+                _ => false,
+            };
+            if removable && self.is_redundant(literal, &clause_variables, trail, &mut redundant) {
+                minimized_clause.remove_literal(literal);
+            }
+        }
+
+        let literals_removed = learned_clause.literals_len() - minimized_clause.literals_len();
+        if literals_removed == 0 {
+            return learned_clause;
+        }
+
+        // Resolve the removed literals, and the redundant literals they introduce, from the latest assigned one.
+        let mut derivations: Vec<(Clause, Clause, Clause)> = Vec::new();
+        let mut clause = learned_clause;
+        loop {
+            let next_literal = clause.iter_literals()
+                .filter(|&&literal| !minimized_clause.contains_literal(literal))
+                .max_by_key(|&&literal| trail.get(&(literal.abs() as usize)).map(|&(_, position, _)| position))
+                .cloned();
+            let literal = match next_literal {
+                Some(literal) => literal,
+                None => break,
+            };
+            let reason_idx = match trail.get(&(literal.abs() as usize)) {
+                Some(&(_, _, Some(reason_idx))) => reason_idx,
+                _ => break,
+            };
+
+            let reason = self.get_clause(reason_idx).clone();
+            let mut resolvent = clause.clone() + reason.clone();
+            resolvent.remove_literal(literal);
+            resolvent.remove_literal(-literal);
+            self.current_learned_clause_id += 1;
+            resolvent.set_id(self.current_learned_clause_id);
+            self.get_mut_clause(reason_idx).learned_clause_is_used_somewhere = true;
+            self.bump_activity(reason_idx);
+
+            derivations.push((clause, reason, resolvent.clone()));
+            clause = resolvent;
+        }
+
+        self.file_print_resolutions(&derivations);
+        self.tex_print_model("Minimize", Some(format!("{}: {}", clause.get_id(), clause)));
+        self.stats.increase_literals_minimized(literals_removed);
+
+        return clause;
+    }
+
+    /// Check if a literal of the learned clause is implied by the other literals of the clause.
+    /// It is redundant if all the other literals of its reason clause are in the learned clause or are redundant themselves.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literal` - The literal to check.
+    /// * `clause_variables` - The variables of the learned clause.
+    /// * `trail` - The trail before the explain function.
+    /// * `redundant` - The variables already checked, with the result of the check.
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the literal is redundant, false otherwise.
+    /// 
+    fn is_redundant(&self, literal: isize, clause_variables: &HashSet<usize>, trail: &HashMap<usize, (usize, usize, Option<usize>)>, redundant: &mut HashMap<usize, bool>) -> bool {
+        let variable = literal.abs() as usize;
+        if let Some(&is_redundant) = redundant.get(&variable) {
+            return is_redundant;
+        }
+
+        let is_redundant = match trail.get(&variable) {
+            Some(&(_, _, Some(reason_idx))) => {
+                self.get_clause(reason_idx).iter_literals().all(|&other| {
+                    let other_variable = other.abs() as usize;
+                    other_variable == variable
+                        || clause_variables.contains(&other_variable)
+                        || self.is_redundant(other, clause_variables, trail, redundant)
+                })
+            },
+            _ => false,
+        };
+
+        redundant.insert(variable, is_redundant);
+        return is_redundant;
+    }
+
+    /// Inprocess function.
+    /// It removes the literals propagated at decision level 0, then it simplifies the formula and the learned clauses.
+    /// 
+    /// # Returns
+    /// 
+    /// * `SAT` - The result of the simplification, Unknown if the search must go on.
+    /// 
+    fn inprocess(&mut self) -> SAT {
+
+        self.remove_latest_propagated_literals();
+
+        self.formula.set_current_clause_id(self.current_learned_clause_id);
+        let inprocessing_start = Instant::now();
+        // The preprocessor rebuilds the learned clauses, their LBD and activity are restored by id.
+        let metadata: HashMap<usize, (usize, f64)> = self.learned_clauses.iter()
+            .map(|clause| (clause.get_id(), (clause.get_lbd(), clause.get_activity())))
+            .collect();
+        let inprocessed = self.preprocessor.inprocess(&mut self.formula, &mut self.learned_clauses);
+        for clause in self.learned_clauses.iter_mut() {
+            if let Some(&(lbd, activity)) = metadata.get(&clause.get_id()) {
+                clause.set_lbd(lbd);
+                clause.set_activity(activity);
+            }
+        }
+        self.stats.add_time(Phase::Inprocessing, inprocessing_start.elapsed());
+        self.current_learned_clause_id = self.formula.get_current_clause_id();
+
+        self.stats.increase_clauses_eliminated(self.preprocessor.get_clauses_eliminated());
+        self.stats.increase_variables_eliminated(self.preprocessor.get_variables_eliminated());
+        self.file_print_preprocessing("Inprocess");
+
+        if inprocessed == SAT::Satisfiable {
+            self.model = Model::new(Some(self.formula.get_num_variables()));
+        }
+
+        return inprocessed;
+    }
+
     /// Remove latest propagated literals function.
     fn remove_latest_propagated_literals(&mut self) {
         self.decisions[self.decision_level].get_propagated_literals().iter().for_each(|&literal| {
             self.model.remove(literal.0);
+            self.heuristic.on_unassign(literal.0);
         });
         self.decisions[self.decision_level].clear_propagated_literals();
         self.formula.get_mut_clauses().iter_mut().for_each(|clause| {
@@ -503,6 +1731,7 @@ impl Solver {
         self.remove_latest_propagated_literals();
         if self.decision_level > 0 {
             self.model.remove(self.decisions[self.decision_level].get_decided_literal());
+            self.heuristic.on_unassign(self.decisions[self.decision_level].get_decided_literal());
             self.decision_level -= 1;
         }
         self.decisions.pop();
@@ -517,123 +1746,99 @@ impl Solver {
 
     }
 
-    /// Update vsids array.
-    /// It reduces the vsids of all literals and increases the vsids of the literals in the conflict clause.
+    /// Returns a random unassigned literal, with the frequency of the random decisions.
     /// 
-    /// # Arguments
+    /// # Returns
     /// 
-    /// * `conflict_clause_idx` - The index of the conflict clause.
+    /// * `Option<isize>` - The literal, None if the heuristic decides.
     /// 
-    fn update_vsids(&mut self, conflict_clause_idx: usize) {
-        let conflict_clause = self.get_clause(conflict_clause_idx);
-        let mut vsids = self.vsids.clone();
-        for literal in vsids.iter_mut() {
-            *literal = (literal.0 / 2.0, literal.1 / 2.0);
-            if literal.0 + literal.1 < f32::EPSILON {
-                *literal = (0.0, 0.0);
-            }
+    fn random_decision(&mut self) -> Option<isize> {
+        if self.random_decision_frequency <= 0.0 || !self.rng.gen_bool(self.random_decision_frequency) {
+            return None;
         }
-        for literal in conflict_clause.iter_literals() {
-            let vsids_literal = vsids.get_mut(literal.abs() as usize - 1);
-            if literal > &0 {
-                vsids_literal.unwrap().0 += 1.0;
-            } else {
-                vsids_literal.unwrap().1 += 1.0;
-            }
+        let unassigned: Vec<usize> = (1..=self.formula.get_num_variables())
+            .filter(|&variable| !self.model.has_abs(variable))
+            .collect();
+        if unassigned.is_empty() {
+            return None;
         }
-        self.vsids = vsids;
+        let variable = unassigned[self.rng.gen_range(0..unassigned.len())] as isize;
+        return Some(if self.rng.gen_bool(0.5) { variable } else { -variable });
     }
 
-    /// Decision function.
-    /// It decides which literal to assign next. It uses the vsids heuristic.
-    /// If the vsids heuristic is not enough, it uses the watched literals, counting the number of occurrences of each literal.
+    /// Returns the next assumption to decide.
     /// 
     /// # Returns
     /// 
-    /// * `isize` - The literal to decide.
+    /// * `Result<Option<isize>, ()>` - The first assumption not assigned yet, None if all the assumptions hold, an error if an assumption is falsified.
     /// 
-    fn decision(&self) -> isize {
-        let vsids = self.vsids.clone();
-        let mut vsids: Vec<(usize, (f32, f32))> = vsids.iter().enumerate().map(|(idx, &value)| (idx, value)).collect();
-        vsids.retain(|e| (e.1.0 + e.1.1) > 0.0 );
-        vsids.sort_by(|a, b| (b.1.0+b.1.1).partial_cmp(&(a.1.0+a.1.1)).unwrap());
-        
-        for (idx, _) in vsids.iter() {
-            if !self.model.has_abs(idx + 1) {
-                let mut sign:isize = 1;
-                if self.vsids[*idx].0 < self.vsids[*idx].1 {
-                    sign = -1;
-                }
-                return (idx + 1) as isize * sign;
-            }
-        }
-
-        let mut literals = self.formula.get_all_watched_literals();
-
-        for clause in &self.learned_clauses {
-            if !clause.is_satisfied() {
-                let literal_tuple = clause.get_watched_literals();
-                literals.push(literal_tuple.0);
-                if literal_tuple.0 != literal_tuple.1 {
-                    literals.push(literal_tuple.1);
-                }
+    fn next_assumption(&self) -> Result<Option<isize>, ()> {
+        for &assumption in self.assumptions.iter() {
+            match self.model.satisfies(assumption) {
+                SAT::Satisfiable => continue,
+                SAT::Unknown => return Ok(Some(assumption)),
+                SAT::Unsatisfiable => return Err(()),
             }
         }
-        
-        let mut literals_count = literals.clone();
-
-        literals_count = literals_count.iter().map(|x| x.abs()).collect::<Vec<isize>>();
-        literals_count.dedup();
-        literals_count.sort();
-
-        literals_count.sort_by(|a, b| literals.iter().filter(|&x| x.abs() == *b).count().cmp(&literals.iter().filter(|&x| x.abs() == *a).count()));
-
-        let literal = literals_count[0];
-
-        let mut sign = 1;
-
-        if literals.iter().filter(|&x| x.abs() == literal).count() / 2 < literals.iter().filter(|&x| *x == literal * -1).count() {
-            sign = -1;
-        }
-
-        return literal * sign;
+        Ok(None)
     }
 
     /// Forget function.
-    /// It forgets half of the learned clauses.
+    /// It deletes the learned clauses selected by the reduction policy, then the limit of the learned clauses grows.
+    /// The learned clauses that are the reason of an assigned literal are never deleted, nor the clause learned last,
+    /// and the reasons of the assigned literals are moved to the new indexes of their clauses.
     fn forget(&mut self) {
 
-        //clone learned clauses and associate them with their id
-        let mut learned_clauses: Vec<(usize, Clause)> = self.learned_clauses.clone()
-                .iter_mut().enumerate().map(|(idx, clause)| (idx, clause.clone())).collect();
-
-        let mut avg_clause_len = 0;
-        for clause in learned_clauses.iter() {
-            avg_clause_len += clause.1.literals_len();
+        let num_clauses = self.formula.get_num_clauses();
+        let mut locked: HashSet<usize> = self.decisions.iter()
+            .flat_map(|decision| decision.get_propagated_literals().iter().map(|&(_, clause_idx)| clause_idx))
+            .filter(|&clause_idx| clause_idx >= num_clauses)
+            .map(|clause_idx| clause_idx - num_clauses)
+            .collect();
+        // The clause learned last is not propagated yet, it becomes unit after the backjump.
+        if !self.learned_clauses.is_empty() {
+            locked.insert(self.learned_clauses.len() - 1);
         }
-        avg_clause_len /= learned_clauses.len();
 
-        let clauses_to_forget_target = self.learned_clauses.len() / 2;
-        let mut clauses_forgotten = 0;
-        let mut append = String::new();
-
-        for clause in learned_clauses.iter_mut() {
-           // if clause.1.literals_len() > 1 && clause.0 < clauses_to_forget_target && clause.1.learned_clause_is_used_somewhere {
-            if clause.0 < clauses_to_forget_target && clause.1.literals_len() > avg_clause_len {
-                self.learned_clauses.retain(|x| x.get_id() != clause.1.get_id());
-                if clauses_forgotten > 0 {
-                    append.push_str(", ");
+        let target = self.reduction_schedule.get_target(self.learned_clauses.len());
+        let selected: HashSet<usize> = self.reduction.select(&self.learned_clauses, &locked, target).into_iter()
+            .filter(|idx| !locked.contains(idx))
+            .collect();
+
+        let mut kept: Vec<Clause> = Vec::new();
+        let mut new_indexes: HashMap<usize, usize> = HashMap::new();
+        let mut forgotten: Vec<String> = Vec::new();
+        for (idx, clause) in std::mem::take(&mut self.learned_clauses).into_iter().enumerate() {
+            if selected.contains(&idx) {
+                forgotten.push(clause.get_id().to_string());
+            } else {
+                if idx != kept.len() {
+                    new_indexes.insert(num_clauses + idx, num_clauses + kept.len());
                 }
-                append.push_str(&format!("{}", clause.1.get_id()));
-                clauses_forgotten += 1;
+                kept.push(clause);
             }
         }
+        self.learned_clauses = kept;
+        self.decisions.iter_mut().for_each(|decision| decision.remap_clause_indexes(&new_indexes));
 
-        self.stats.increase_forgotten(clauses_forgotten);
-        self.max_learned_clauses = (self.max_learned_clauses as f32 * 1.5).round() as usize;
+        self.stats.increase_forgotten(forgotten.len());
+        self.reduction_schedule.next();
 
-        self.tex_print_model("Forget", Some(format!("{}", append)));
+        self.tex_print_model("Forget", Some(forgotten.join(", ")));
+
+    }
 
+    /// Increase the activity of a learned clause used in the conflict analysis.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `clause_idx` - The index of the clause, nothing is done if it is a clause of the formula.
+    /// 
+    fn bump_activity(&mut self, clause_idx: usize) {
+        if clause_idx >= self.formula.get_num_clauses() {
+            let clause = self.get_mut_clause(clause_idx);
+            clause.set_activity(clause.get_activity() + 1.0);
+        }
     }
 
     /// Print the arrow in the tex proof.
@@ -648,6 +1853,32 @@ impl Solver {
         }
     }
 
+    /// Resolve two clauses and print the resolution to the proof files, the empty clause ends the tex proof.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `first` - The first clause.
+    /// * `second` - The second clause.
+    /// * `literal` - The literal resolved, with either sign.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Clause` - The resolvent, with a new id.
+    /// 
+    fn resolve(&mut self, first: &Clause, second: &Clause, literal: isize) -> Clause {
+        let mut resolvent = first.clone() + second.clone();
+        resolvent.remove_literal(literal);
+        resolvent.remove_literal(-literal);
+        self.current_learned_clause_id += 1;
+        resolvent.set_id(self.current_learned_clause_id);
+        self.file_print_resolutions(&vec![(first.clone(), second.clone(), resolvent.clone())]);
+        if resolvent.literals_len() == 0 && self.print_tex_proof {
+            self.tex_print_arrow("Fail");
+            self.file_tex.writeln("$\\square$");
+        }
+        return resolvent;
+    }
+
     /// Print the model in the tex proof.
     /// 
     /// # Arguments
@@ -675,6 +1906,22 @@ impl Solver {
                     i = 0;
                 }
             }
+            self.tex_print_state(arrow_str, model_string, append);
+        }
+    }
+
+    /// Print a state of the search to the tex proof file, the learned clauses are added to the formula.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `arrow_str` - The label of the transition to the state.
+    /// * `model_string` - The assigned literals, formatted for the proof.
+    /// * `append` - The text printed after the formula, if any.
+    /// 
+    fn tex_print_state(&mut self, arrow_str: &str, mut model_string: String, append: Option<String>) {
+
+        if self.print_tex_proof {
+
             if model_string.len() == 0 {
                 model_string.push_str("\\emptyset");
             }
@@ -743,6 +1990,63 @@ impl Solver {
         }
     }
 
+    /// Write the resolution steps of the preprocessing in the proof files.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `arrow_str` - The string to print below the arrow in the tex proof.
+    /// 
+    fn file_print_preprocessing(&mut self, arrow_str: &str) {
+
+        let derivations = self.preprocessor.get_derivations().clone();
+        self.file_print_resolutions(&derivations);
+
+        self.tex_print_model(arrow_str, Some(format!(
+            "{}\\ clauses\\ and\\ {}\\ variables\\ eliminated",
+            self.preprocessor.get_clauses_eliminated(),
+            self.preprocessor.get_variables_eliminated()
+        )));
+    }
+
+    /// Print resolution steps in the dot and txt proofs.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `derivations` - The resolution steps, as the two resolved clauses and the resolvent.
+    /// 
+    fn file_print_resolutions(&mut self, derivations: &Vec<(Clause, Clause, Clause)>) {
+
+        if self.print_dot_proof || self.print_txt_proof {
+            for (first, second, resolvent) in derivations.iter() {
+                let resolvent_formatted: String;
+                let mut txt_formatted = format!("({}) {} - ({}) {} => ", first.get_id(), first, second.get_id(), second);
+
+                if resolvent.literals_len() == 0 {
+                    resolvent_formatted = "□".to_string();
+                    txt_formatted.push_str("□");
+                } else {
+                    resolvent_formatted = format!("{id} [label=<<FONT POINT-SIZE='8.0'>({id})  </FONT>{clause}>]", id=resolvent.get_id(), clause=resolvent);
+                    txt_formatted.push_str(format!("({}) {}", resolvent.get_id(), resolvent).as_str());
+                }
+
+                if self.print_dot_proof {
+                    let resolvent_node = match resolvent.literals_len() {
+                        0 => "□".to_string(),
+                        _ => resolvent.get_id().to_string(),
+                    };
+                    self.file_dot.writeln(&format!("{id} [label=<<FONT POINT-SIZE='8.0'>({id})  </FONT>{clause}>]", id=first.get_id(), clause=first));
+                    self.file_dot.writeln(&format!("{id} [label=<<FONT POINT-SIZE='8.0'>({id})  </FONT>{clause}>]", id=second.get_id(), clause=second));
+                    self.file_dot.writeln(&resolvent_formatted);
+                    self.file_dot.writeln(&format!("{} -> {}", first.get_id(), resolvent_node));
+                    self.file_dot.writeln(&format!("{} -> {}", second.get_id(), resolvent_node));
+                }
+                if self.print_txt_proof {
+                    self.file_txt.writeln(&txt_formatted);
+                }
+            }
+        }
+    }
+
     /// Close the proof files.
     fn file_close(&mut self) {
         if self.print_dot_proof {
@@ -767,12 +2071,135 @@ impl Solver {
         }
     }
 
+    /// Returns the statistics of the solver as a JSON object.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `file_name` - The name of the solved instance.
+    /// * `sat` - The result of the solver.
+    /// * `time` - The time elapsed solving the instance.
+    /// 
+    /// # Returns
+    /// 
+    /// * `String` - The JSON object with the instance metadata and all the statistics.
+    /// 
+    pub fn get_stats_json(&self, file_name: &str, sat: SAT, time: Duration) -> String {
+        return self.stats.to_json(&self.get_stats_metadata(file_name, sat, time));
+    }
+
+    /// Returns the statistics of the solver as a CSV row.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `file_name` - The name of the solved instance.
+    /// * `sat` - The result of the solver.
+    /// * `time` - The time elapsed solving the instance.
+    /// * `header` - True to write the header row before the values.
+    /// 
+    /// # Returns
+    /// 
+    /// * `String` - The CSV row with the instance metadata and all the statistics.
+    /// 
+    pub fn get_stats_csv(&self, file_name: &str, sat: SAT, time: Duration, header: bool) -> String {
+        return self.stats.to_csv(&self.get_stats_metadata(file_name, sat, time), header);
+    }
+
+    /// Returns the metadata of the solved instance, with values in JSON format.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `file_name` - The name of the solved instance.
+    /// * `sat` - The result of the solver.
+    /// * `time` - The time elapsed solving the instance.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<(&str, String)>` - The name and the value of each field.
+    /// 
+    fn get_stats_metadata(&self, file_name: &str, sat: SAT, time: Duration) -> Vec<(&'static str, String)> {
+        return vec![
+            ("file", Stats::json_string(file_name)),
+            ("variables", self.formula.get_num_variables().to_string()),
+            ("clauses", self.formula.get_num_clauses().to_string()),
+            ("xors", self.formula.get_xors().len().to_string()),
+            ("engine", Stats::json_string(&self.engine.to_string())),
+            ("workers", self.portfolio_workers.to_string()),
+            ("fragment", Stats::json_string(&self.formula.get_fragment().to_string())),
+            ("heuristic", Stats::json_string(self.heuristic.name())),
+            ("reduction", Stats::json_string(self.reduction.name())),
+            ("result", Stats::json_string(&sat.to_string())),
+            ("time", format!("{:.6}", time.as_secs_f64())),
+        ];
+    }
+
     /// Print the statistics of the solver.
     pub fn print_stats(&self) {
+        if let Some(limit) = self.stats.get_limit_reached() {
+            println!("Search stopped: {}", limit);
+        }
+        println!("Decisions: {} ({:.0}/s)", self.stats.get_decisions(), self.stats.get_rate(self.stats.get_decisions()));
+        println!("Propagations: {} ({:.0}/s)", self.stats.get_propagations(), self.stats.get_rate(self.stats.get_propagations()));
+        println!("Conflicts: {} ({:.0}/s)", self.stats.get_conflicts(), self.stats.get_rate(self.stats.get_conflicts()));
+        // Only the local search restarts, with a new random assignment for every try.
+        if let Engine::WalkSat | Engine::ProbSat = self.engine {
+            println!("Restarts: {}", self.stats.get_restarts());
+            println!("Flips: {} ({:.0}/s)", self.stats.get_flips(), self.stats.get_rate(self.stats.get_flips()));
+        }
+        let (backjumps, average_distance, max_distance) = self.stats.get_backjumps();
+        println!("Backjumps: {} (avg distance {:.2}, max {})", backjumps, average_distance, max_distance);
         println!("Clauses learned: {}", self.stats.get_clauses_learned());
         println!("Clauses forgotten: {}", self.stats.get_clauses_forgotten());
+        if !self.workers.is_empty() {
+            println!("Clauses exported: {}", self.stats.get_clauses_exported());
+            println!("Clauses imported: {}", self.stats.get_clauses_imported());
+        }
+        if self.stats.get_clauses_learned() > 0 {
+            println!("Learned clause length: {}", Stats::format_distribution(self.stats.get_learned_lengths()));
+            println!("Learned clause LBD: {}", Stats::format_distribution(self.stats.get_learned_lbds()));
+        }
+        for phase in Phase::ALL {
+            if phase as usize == Phase::Search as usize {
+                continue;
+            }
+            let time = self.stats.get_time(phase);
+            if time > Duration::ZERO {
+                println!("{} time: {:?}", phase, time);
+            }
+        }
+        println!("Propagation time: {:?}", self.stats.get_propagation_time());
+        println!("Search time: {:?}", self.stats.get_time(Phase::Search));
         println!("Max virtual memory: {}", self.stats.get_virtual_memory());
         println!("Max physical memory: {}", self.stats.get_physical_memory());
+        if self.minimization {
+            println!("Literals removed by minimization: {} ({:.2} per conflict)", self.stats.get_literals_minimized(), self.stats.get_literals_minimized() as f64 / self.stats.get_clauses_learned().max(1) as f64);
+        }
+        if self.preprocessing {
+            println!("Clauses eliminated by preprocessing: {}", self.stats.get_clauses_eliminated());
+            println!("Variables eliminated by preprocessing: {}", self.stats.get_variables_eliminated());
+        }
+        for (idx, worker) in self.workers.iter().enumerate() {
+            let result = match &worker.result {
+                Ok(sat) => sat.to_string(),
+                Err(()) => "error".to_string(),
+            };
+            println!(
+                "Worker {} ({}): {} in {:?}, {} conflicts, {} clauses exported, {} imported",
+                idx + 1, worker.name, result, worker.time, worker.stats.get_conflicts(),
+                worker.stats.get_clauses_exported(), worker.stats.get_clauses_imported()
+            );
+        }
+        if !self.gauss.is_empty() {
+            println!("XOR constraints: {} ({} propagations, {} conflicts)", self.gauss.len(), self.stats.get_xor_propagations(), self.stats.get_xor_conflicts());
+        }
+        if self.engine == Engine::CubeAndConquer {
+            println!("Cubes: {} ({} refuted)", self.stats.get_cubes(), self.stats.get_cubes_refuted());
+        }
+        if let Some((backbone_size, num_variables)) = self.stats.get_backbone() {
+            println!("Backbone: {}/{} variables fixed ({:.2}%)", backbone_size, num_variables, backbone_size as f64 * 100.0 / num_variables as f64);
+        }
+        if let Some(objective) = self.stats.get_objective() {
+            println!("Objective: {} ({})", objective, self.optimization);
+        }
     }
 
 }
\ No newline at end of file
@@ -0,0 +1,157 @@
+use std::thread;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::consts::{sat::SAT, phase::Phase};
+use crate::classes::model::Model;
+use super::Solver;
+use super::portfolio::{ClauseExchange, Worker};
+
+impl Solver {
+
+    /// Solve the formula with cube-and-conquer: the formula is split in cubes by a lookahead, then the cubes are solved
+    /// in parallel by the workers.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<SAT, ()>` - The result of the formula.
+    /// 
+    pub(super) fn solve_cube_and_conquer(&mut self) -> Result<SAT, ()> {
+        match self.cube()? {
+            Some(cubes) if cubes.is_empty() => {
+                self.model = Model::new(Some(self.formula.get_num_variables()));
+                return Ok(SAT::Unsatisfiable);
+            },
+            Some(cubes) => return self.conquer(&cubes),
+            None => {
+                self.model = Model::new(Some(self.formula.get_num_variables()));
+                return Ok(SAT::Unknown);
+            },
+        }
+    }
+
+    /// Split the formula in cubes with a lookahead, every cube is a set of assumptions and the cubes cover every model of the formula.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<Option<Vec<Vec<isize>>>, ()>` - The cubes, empty if the lookahead refutes the formula, None if a limit is reached,
+    /// an error if the formula is not loaded.
+    /// 
+    pub fn cube(&mut self) -> Result<Option<Vec<Vec<isize>>>, ()> {
+
+        if !self.is_formula_loaded() {
+            return Err(());
+        }
+
+        self.limits.start();
+        let lookahead_start = Instant::now();
+        let cubes = self.cuber.cube(&self.formula, &self.limits, &mut self.stats);
+        self.stats.add_time(Phase::Lookahead, lookahead_start.elapsed());
+        self.stats.update();
+
+        if cubes.is_none() {
+            if let Some(limit) = self.limits.check(&self.stats) {
+                self.stats.set_limit_reached(limit);
+            }
+        }
+        return Ok(cubes);
+    }
+
+    /// Solve the formula under every cube.
+    /// Each worker is an incremental solver that takes the next cube until they are all refuted, its learned clauses are
+    /// kept between the cubes and the short ones are shared with the other workers. All the workers stop as soon as one
+    /// of them finds a model.
+    /// The number of workers is the one of the portfolio, the proofs are not written.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `cubes` - The cubes, they must cover every model of the formula.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<SAT, ()>` - Satisfiable if a cube has a model, unsatisfiable if every cube is refuted, an error if the formula
+    /// is not loaded or a literal of a cube is not a variable of the formula.
+    /// 
+    pub fn conquer(&mut self, cubes: &Vec<Vec<isize>>) -> Result<SAT, ()> {
+
+        if !self.is_formula_loaded() {
+            return Err(());
+        }
+        if cubes.iter().flatten().any(|&literal| literal == 0 || literal.abs() as usize > self.formula.get_num_variables()) {
+            return Err(());
+        }
+
+        self.limits.start();
+        let exchange = Arc::new(ClauseExchange::new());
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        let next_cube = AtomicUsize::new(0);
+
+        let num_workers = self.portfolio_workers.min(cubes.len()).max(1);
+        let workers: Vec<Solver> = (0..num_workers).map(|worker| {
+            let mut solver = self.fork(self.heuristic.name());
+            if num_workers > 1 {
+                solver.exchange = Some((exchange.clone(), worker));
+            }
+            solver.limits.set_timeout(self.limits.get_remaining_timeout());
+            solver.limits.set_stop_flag(Some(stop_flag.clone()));
+            solver
+        }).collect();
+
+        let results: Vec<(Solver, Result<SAT, ()>, Duration)> = thread::scope(|scope| {
+            let handles: Vec<_> = workers.into_iter().map(|mut solver| {
+                let stop_flag = stop_flag.clone();
+                let next_cube = &next_cube;
+                scope.spawn(move || {
+                    let start = Instant::now();
+                    solver.limits.start();
+                    let mut result = Ok(SAT::Unsatisfiable);
+                    while let Some(cube) = cubes.get(next_cube.fetch_add(1, Ordering::SeqCst)) {
+                        result = solver.search(cube, false);
+                        match result {
+                            Ok(SAT::Unsatisfiable) => solver.stats.increase_cubes_refuted(),
+                            Ok(SAT::Satisfiable) => {
+                                stop_flag.store(true, Ordering::SeqCst);
+                                break;
+                            },
+                            _ => break,
+                        }
+                    }
+                    (solver, result, start.elapsed())
+                })
+            }).collect();
+            handles.into_iter().map(|handle| handle.join().expect("a worker of the conquer phase panicked")).collect()
+        });
+
+        results.iter().for_each(|(solver, _, _)| self.stats.merge(&solver.stats));
+
+        let result = match results.iter().find(|(_, result, _)| *result == Ok(SAT::Satisfiable)) {
+            Some((solver, _, _)) => {
+                self.model = solver.model.clone();
+                Ok(SAT::Satisfiable)
+            },
+            None => {
+                self.model = Model::new(Some(self.formula.get_num_variables()));
+                match results.iter().map(|(_, result, _)| result.clone()).find(|result| *result != Ok(SAT::Unsatisfiable)) {
+                    Some(result) => {
+                        if let Some(limit) = results.iter().find_map(|(solver, _, _)| solver.stats.get_limit_reached()) {
+                            self.stats.set_limit_reached(limit);
+                        }
+                        result
+                    },
+                    None => Ok(SAT::Unsatisfiable),
+                }
+            },
+        };
+
+        self.workers = results.into_iter().map(|(solver, result, time)| Worker {
+            name: format!("{} cubes refuted", solver.stats.get_cubes_refuted()),
+            result,
+            time,
+            stats: solver.stats,
+        }).collect();
+
+        return result;
+    }
+
+}
\ No newline at end of file
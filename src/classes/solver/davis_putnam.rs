@@ -0,0 +1,207 @@
+use std::time::Instant;
+use std::collections::HashSet;
+
+use crate::consts::{sat::SAT, phase::Phase};
+use crate::classes::{clause::Clause, model::Model};
+use super::Solver;
+
+/// A step of the Davis-Putnam procedure, replayed backwards to build the model.
+enum Step {
+    /// A unit clause or a pure literal.
+    Assign(isize),
+    /// An eliminated variable with the clauses that contained it.
+    Eliminate(usize, Vec<Clause>),
+}
+
+impl Solver {
+
+    /// Solve the formula with the original Davis-Putnam procedure.
+    /// The variables are eliminated by resolution, after the one-literal rule and the pure literal rule, until the empty clause
+    /// is derived or every clause is removed. Every resolvent is written in the proof files.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<SAT, ()>` - The result of the formula.
+    /// 
+    pub(super) fn solve_davis_putnam(&mut self) -> Result<SAT, ()> {
+
+        self.model = Model::new(Some(self.formula.get_num_variables()));
+        self.current_learned_clause_id = self.formula.get_current_clause_id();
+        self.file_init();
+
+        let search_start = Instant::now();
+        let result = self.davis_putnam();
+        self.stats.add_time(Phase::Search, search_start.elapsed());
+        self.stats.update();
+
+        match result {
+            SAT::Satisfiable => self.file_delete(),
+            _ => self.file_close(),
+        }
+        return Ok(result);
+    }
+
+    /// Main loop of the Davis-Putnam procedure.
+    /// 
+    /// # Returns
+    /// 
+    /// * `SAT` - The result of the formula, unknown if a limit is reached.
+    /// 
+    fn davis_putnam(&mut self) -> SAT {
+
+        let mut clauses: Vec<Clause> = self.formula.get_clauses().iter()
+            .filter(|clause| !clause.iter_literals().any(|&literal| clause.contains_literal(-literal)))
+            .cloned()
+            .collect();
+        let mut steps: Vec<Step> = Vec::new();
+
+        loop {
+            if self.progress.is_due(&self.stats) {
+                self.progress.report(&self.stats, clauses.len(), 0.0);
+            }
+            if let Some(limit) = self.limits.check(&self.stats) {
+                self.stats.set_limit_reached(limit);
+                return SAT::Unknown;
+            }
+            self.stats.update();
+
+            if clauses.iter().any(|clause| clause.literals_len() == 0) {
+                return SAT::Unsatisfiable;
+            }
+            if clauses.is_empty() {
+                break;
+            }
+
+            // One-literal rule.
+            if let Some(unit_clause) = clauses.iter().find(|clause| clause.literals_len() == 1).cloned() {
+                let literal = unit_clause.get_literal(0);
+                steps.push(Step::Assign(literal));
+                self.stats.increase_propagations();
+                self.tex_print_steps("Unit", &steps, Some(format!("{}", literal)));
+
+                let mut remaining: Vec<Clause> = Vec::new();
+                for clause in clauses.into_iter() {
+                    if clause.contains_literal(literal) {
+                        self.stats.increase_clauses_eliminated(1);
+                    } else if clause.contains_literal(-literal) {
+                        remaining.push(self.resolve(&clause, &unit_clause, literal));
+                    } else {
+                        remaining.push(clause);
+                    }
+                }
+                clauses = remaining;
+                continue;
+            }
+
+            // Pure literal rule.
+            let mut occurrences: HashSet<isize> = HashSet::new();
+            clauses.iter().for_each(|clause| occurrences.extend(clause.iter_literals()));
+            if let Some(&literal) = occurrences.iter().filter(|&&literal| !occurrences.contains(&-literal)).min() {
+                let before = clauses.len();
+                clauses.retain(|clause| !clause.contains_literal(literal));
+                self.stats.increase_clauses_eliminated(before - clauses.len());
+                steps.push(Step::Assign(literal));
+                self.tex_print_steps("Pure", &steps, Some(format!("{}", literal)));
+                continue;
+            }
+
+            // Elimination of the variable with the fewest resolvents.
+            let variable = occurrences.iter()
+                .map(|&literal| literal.abs())
+                .min_by_key(|&variable| {
+                    let positive = clauses.iter().filter(|clause| clause.contains_literal(variable)).count();
+                    let negative = clauses.iter().filter(|clause| clause.contains_literal(-variable)).count();
+                    (positive * negative, variable)
+                })
+                .unwrap();
+            let (eliminated, mut remaining): (Vec<Clause>, Vec<Clause>) = clauses.into_iter()
+                .partition(|clause| clause.contains_literal(variable) || clause.contains_literal(-variable));
+
+            let mut resolvents = 0;
+            for positive in eliminated.iter().filter(|clause| clause.contains_literal(variable)) {
+                if let Some(limit) = self.limits.check(&self.stats) {
+                    self.stats.set_limit_reached(limit);
+                    return SAT::Unknown;
+                }
+                for negative in eliminated.iter().filter(|clause| clause.contains_literal(-variable)) {
+                    // The tautologies and the resolvents subsumed by a remaining clause are not added.
+                    let tautology = positive.iter_literals().any(|&literal| literal != variable && negative.contains_literal(-literal));
+                    if tautology {
+                        continue;
+                    }
+                    let subsumed = remaining.iter().any(|clause| clause.iter_literals().all(|&literal| {
+                        literal.abs() != variable && (positive.contains_literal(literal) || negative.contains_literal(literal))
+                    }));
+                    if !subsumed {
+                        remaining.push(self.resolve(positive, negative, variable));
+                        resolvents += 1;
+                    }
+                }
+            }
+
+            self.stats.increase_variables_eliminated(1);
+            self.stats.increase_clauses_eliminated(eliminated.len());
+            clauses = remaining;
+            steps.push(Step::Eliminate(variable as usize, eliminated));
+            self.tex_print_steps("Eliminate", &steps, Some(format!("{}: {} resolvents", variable, resolvents)));
+        }
+
+        // The variables removed with their clauses are free, then the steps are replayed from the last one.
+        let mut assigned = vec![false; self.formula.get_num_variables() + 1];
+        for step in steps.iter() {
+            match step {
+                Step::Assign(literal) => assigned[literal.abs() as usize] = true,
+                Step::Eliminate(variable, _) => assigned[*variable] = true,
+            }
+        }
+        for variable in 1..assigned.len() {
+            if !assigned[variable] {
+                self.model.add(-(variable as isize));
+            }
+        }
+        for step in steps.iter().rev() {
+            match step {
+                Step::Assign(literal) => self.model.add(*literal),
+                Step::Eliminate(variable, eliminated) => {
+                    let variable = *variable as isize;
+                    // The variable is true only if a clause with the positive literal is not satisfied by the other literals.
+                    let needed = eliminated.iter()
+                        .filter(|clause| clause.contains_literal(variable))
+                        .any(|clause| !clause.iter_literals().any(|&literal| literal != variable && self.model.has(literal)));
+                    self.model.add(if needed { variable } else { -variable });
+                },
+            }
+        }
+
+        return SAT::Satisfiable;
+    }
+
+    /// Print the literals assigned by the Davis-Putnam procedure in the tex proof.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `arrow_str` - The string to print below the arrow.
+    /// * `steps` - The steps of the procedure.
+    /// * `append` - The string to append to the model.
+    /// 
+    fn tex_print_steps(&mut self, arrow_str: &str, steps: &Vec<Step>, append: Option<String>) {
+
+        if self.print_tex_proof {
+
+            let mut model_string: String = String::new();
+            let mut i = 0;
+            for step in steps.iter() {
+                if let Step::Assign(literal) = step {
+                    model_string.push_str(&format!("{} ", literal));
+                    i += 1;
+                    if i > 40 {
+                        model_string.push_str("\n");
+                        i = 0;
+                    }
+                }
+            }
+            self.tex_print_state(arrow_str, model_string, append);
+        }
+    }
+
+}
\ No newline at end of file
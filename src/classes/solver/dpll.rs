@@ -0,0 +1,301 @@
+use std::time::Instant;
+
+use crate::consts::{sat::SAT, phase::Phase};
+use crate::classes::{clause::Clause, model::Model};
+use super::Solver;
+
+/// The reason of an assignment of the DPLL search.
+#[derive(Clone, Copy)]
+enum Reason {
+    Decision,
+    Propagation(usize),
+    Pure,
+}
+
+/// The outcome of a node of the DPLL search.
+enum Outcome {
+    Satisfiable,
+    /// The clause is derived by resolution from the formula and it is falsified by the assignment of the node.
+    Conflict(Clause),
+    Unknown,
+}
+
+impl Solver {
+
+    /// Solve the formula with the classic recursive DPLL, without learned clauses.
+    /// Every failed branch is explained by a clause derived by resolution, so an unsatisfiable formula has a tree-like
+    /// resolution proof, the same proof style of CDCL.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<SAT, ()>` - The result of the formula.
+    /// 
+    pub(super) fn solve_dpll(&mut self) -> Result<SAT, ()> {
+
+        self.model = Model::new(Some(self.formula.get_num_variables()));
+        self.heuristic.resize(self.formula.get_num_variables());
+        self.current_learned_clause_id = self.formula.get_current_clause_id();
+        self.file_init();
+
+        let search_start = Instant::now();
+        let mut trail: Vec<(isize, Reason)> = Vec::new();
+        let outcome = self.dpll(&mut trail);
+        self.stats.add_time(Phase::Search, search_start.elapsed());
+        self.stats.update();
+
+        match outcome {
+            Outcome::Satisfiable => {
+                self.file_delete();
+                return Ok(SAT::Satisfiable);
+            },
+            Outcome::Conflict(_) => {
+                self.file_close();
+                return Ok(SAT::Unsatisfiable);
+            },
+            Outcome::Unknown => {
+                self.file_close();
+                return Ok(SAT::Unknown);
+            },
+        }
+    }
+
+    /// A node of the DPLL search, it propagates the unit clauses and the pure literals, then it tries both values of a literal.
+    /// The assignments of the node are undone if the node fails.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `trail` - The assigned literals with their reasons.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Outcome` - Satisfiable if the model satisfies the formula, the explanation of the failure or unknown if a limit is reached.
+    /// 
+    fn dpll(&mut self, trail: &mut Vec<(isize, Reason)>) -> Outcome {
+
+        let start = trail.len();
+
+        if self.progress.is_due(&self.stats) {
+            self.progress.report(&self.stats, 0, 0.0);
+        }
+        if let Some(limit) = self.limits.check(&self.stats) {
+            self.stats.set_limit_reached(limit);
+            return Outcome::Unknown;
+        }
+
+        // Unit propagation.
+        let mut conflict_clause_idx: Option<usize> = None;
+        loop {
+            match self.dpll_next_unit_clause() {
+                Ok(Some((literal, clause_idx))) => {
+                    self.dpll_assign(trail, literal, Reason::Propagation(clause_idx));
+                    self.stats.increase_propagations();
+                    self.tex_print_trail("Propagation", trail, None);
+                },
+                Ok(None) => break,
+                Err(clause_idx) => {
+                    conflict_clause_idx = Some(clause_idx);
+                    break;
+                },
+            }
+        }
+
+        let explanation = match conflict_clause_idx {
+            Some(clause_idx) => {
+                let conflict_clause = self.formula.get_clause(clause_idx).clone();
+                self.stats.increase_conflicts();
+                self.heuristic.on_conflict(&conflict_clause, &conflict_clause);
+                self.tex_print_trail("Conflict", trail, Some(format!("{}: {}", conflict_clause.get_id(), conflict_clause)));
+                conflict_clause
+            },
+            None => {
+                // A pure literal never appears negated in an unsatisfied clause, so it is never part of an explanation.
+                let pure_literals = self.dpll_pure_literals();
+                for &literal in pure_literals.iter() {
+                    self.dpll_assign(trail, literal, Reason::Pure);
+                }
+                if !pure_literals.is_empty() {
+                    self.tex_print_trail("Pure", trail, None);
+                }
+
+                let satisfied = self.formula.get_clauses().iter().all(|clause| {
+                    clause.iter_literals().any(|&literal| self.model.has(literal))
+                });
+                if satisfied {
+                    return Outcome::Satisfiable;
+                }
+
+                let decision_start = Instant::now();
+                let decided_literal = self.heuristic.decide(&self.model, self.formula.get_clauses(), &Vec::new());
+                self.stats.add_time(Phase::Decision, decision_start.elapsed());
+                let decided_literal = match decided_literal {
+                    Some(literal) => literal,
+                    None => return Outcome::Satisfiable,
+                };
+                self.stats.increase_decisions();
+                self.stats.update();
+
+                let first = match self.dpll_branch(trail, decided_literal, "Decision") {
+                    Outcome::Conflict(clause) => clause,
+                    outcome => return outcome,
+                };
+                // The other branch is skipped if the decision is not needed to explain the failure.
+                if first.contains_literal(-decided_literal) {
+                    self.stats.add_backjump(1);
+                    let second = match self.dpll_branch(trail, -decided_literal, "Backtrack") {
+                        Outcome::Conflict(clause) => clause,
+                        outcome => return outcome,
+                    };
+                    if second.contains_literal(decided_literal) {
+                        self.resolve(&first, &second, decided_literal)
+                    } else {
+                        second
+                    }
+                } else {
+                    first
+                }
+            },
+        };
+
+        // Resolve the literals propagated by the node, from the latest one.
+        let mut explanation = explanation;
+        let propagated: Vec<(isize, usize)> = trail[start..].iter().rev()
+            .filter_map(|&(literal, reason)| match reason {
+                Reason::Propagation(clause_idx) => Some((literal, clause_idx)),
+                _ => None,
+            })
+            .collect();
+        for (literal, clause_idx) in propagated {
+            if explanation.contains_literal(-literal) {
+                let reason = self.formula.get_clause(clause_idx).clone();
+                explanation = self.resolve(&explanation, &reason, literal);
+            }
+        }
+
+        while trail.len() > start {
+            let (literal, _) = trail.pop().unwrap();
+            self.model.remove(literal);
+            self.heuristic.on_unassign(literal);
+        }
+
+        return Outcome::Conflict(explanation);
+    }
+
+    /// Decide a literal and search the subtree, the decision is undone if the subtree fails.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `trail` - The assigned literals with their reasons.
+    /// * `literal` - The decided literal.
+    /// * `arrow_str` - The label of the decision in the tex proof.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Outcome` - The outcome of the subtree.
+    /// 
+    fn dpll_branch(&mut self, trail: &mut Vec<(isize, Reason)>, literal: isize, arrow_str: &str) -> Outcome {
+        self.dpll_assign(trail, literal, Reason::Decision);
+        self.tex_print_trail(arrow_str, trail, None);
+
+        let outcome = self.dpll(trail);
+        if let Outcome::Conflict(_) = outcome {
+            trail.pop();
+            self.model.remove(literal);
+            self.heuristic.on_unassign(literal);
+        }
+        return outcome;
+    }
+
+    /// Assign a literal and add it to the trail.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `trail` - The assigned literals with their reasons.
+    /// * `literal` - The literal to assign.
+    /// * `reason` - The reason of the assignment.
+    /// 
+    fn dpll_assign(&mut self, trail: &mut Vec<(isize, Reason)>, literal: isize, reason: Reason) {
+        self.model.add(literal);
+        self.heuristic.on_assign(literal);
+        trail.push((literal, reason));
+    }
+
+    /// Find the next unit clause of the formula under the model.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<Option<(isize, usize)>, usize>` - The unassigned literal and the index of the unit clause, None if there is no unit clause,
+    /// an error with the index of the clause if a clause is falsified.
+    /// 
+    fn dpll_next_unit_clause(&self) -> Result<Option<(isize, usize)>, usize> {
+        let mut unit: Option<(isize, usize)> = None;
+        for (clause_idx, clause) in self.formula.get_clauses().iter().enumerate() {
+            if clause.iter_literals().any(|&literal| self.model.has(literal)) {
+                continue;
+            }
+            let mut unassigned = clause.iter_literals().filter(|&&literal| !self.model.has(-literal));
+            match (unassigned.next(), unassigned.next()) {
+                (None, _) => return Err(clause_idx),
+                (Some(&literal), None) if unit.is_none() => unit = Some((literal, clause_idx)),
+                _ => (),
+            }
+        }
+        return Ok(unit);
+    }
+
+    /// Find the pure literals, the unassigned literals whose negation does not appear in any unsatisfied clause.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<isize>` - The pure literals.
+    /// 
+    fn dpll_pure_literals(&self) -> Vec<isize> {
+        let num_variables = self.formula.get_num_variables();
+        let mut positive = vec![false; num_variables + 1];
+        let mut negative = vec![false; num_variables + 1];
+        for clause in self.formula.get_clauses().iter() {
+            if clause.iter_literals().any(|&literal| self.model.has(literal)) {
+                continue;
+            }
+            for &literal in clause.iter_literals().filter(|&&literal| !self.model.has(-literal)) {
+                if literal > 0 {
+                    positive[literal as usize] = true;
+                } else {
+                    negative[literal.abs() as usize] = true;
+                }
+            }
+        }
+        return (1..=num_variables)
+            .filter(|&variable| positive[variable] != negative[variable])
+            .map(|variable| if positive[variable] { variable as isize } else { -(variable as isize) })
+            .collect();
+    }
+
+    /// Print the trail of the DPLL search in the tex proof.
+    /// The decided literals are marked with d, the pure literals with p and the propagated literals with the index of their clause.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `arrow_str` - The string to print below the arrow.
+    /// * `trail` - The assigned literals with their reasons.
+    /// * `append` - The string to append to the model.
+    /// 
+    fn tex_print_trail(&mut self, arrow_str: &str, trail: &Vec<(isize, Reason)>, append: Option<String>) {
+
+        if self.print_tex_proof {
+
+            let mut model_string: String = String::new();
+            for (i, &(literal, reason)) in trail.iter().enumerate() {
+                match reason {
+                    Reason::Decision => model_string.push_str(&format!("{}{{^d}} ", literal)),
+                    Reason::Propagation(clause_idx) => model_string.push_str(&format!("{}{{_{{{}}}}}", literal, clause_idx + 1)),
+                    Reason::Pure => model_string.push_str(&format!("{}{{^p}} ", literal)),
+                }
+                if i % 40 == 39 {
+                    model_string.push_str("\n");
+                }
+            }
+            self.tex_print_state(arrow_str, model_string, append);
+        }
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,226 @@
+use std::time::Instant;
+use std::collections::VecDeque;
+
+use crate::consts::{fragment::Fragment, sat::SAT, phase::Phase};
+use crate::classes::{clause::Clause, model::Model, preprocessor::Preprocessor};
+use super::Solver;
+
+impl Solver {
+
+    /// Solve a 2-CNF, Horn or dual-Horn formula in linear time.
+    /// A 2-CNF formula is solved with the strongly connected components of its implication graph, a Horn or dual-Horn
+    /// formula with unit propagation. An unsatisfiable formula has a resolution proof, the same proof style of CDCL.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<SAT, ()>` - The result of the formula.
+    /// 
+    pub(super) fn solve_fragment(&mut self) -> Result<SAT, ()> {
+
+        self.model = Model::new(Some(self.formula.get_num_variables()));
+        self.current_learned_clause_id = self.formula.get_current_clause_id();
+        self.file_init();
+
+        let search_start = Instant::now();
+        let result = match self.formula.get_fragment() {
+            Fragment::TwoCnf => self.solve_two_sat(),
+            Fragment::DualHorn => self.solve_horn(true),
+            _ => self.solve_horn(false),
+        };
+        self.stats.add_time(Phase::Search, search_start.elapsed());
+        self.stats.update();
+
+        match result {
+            SAT::Satisfiable => self.file_delete(),
+            _ => self.file_close(),
+        }
+        return Ok(result);
+    }
+
+    /// Solve a 2-CNF formula with the implication graph: every clause (a, b) is the implications -a -> b and -b -> a.
+    /// The formula is unsatisfiable if a literal and its negation are in the same strongly connected component, otherwise
+    /// a literal is true if its component comes after the one of its negation in topological order.
+    /// 
+    /// # Returns
+    /// 
+    /// * `SAT` - The result of the formula.
+    /// 
+    fn solve_two_sat(&mut self) -> SAT {
+
+        if self.formula.get_clauses().iter().any(|clause| clause.literals_len() == 0) {
+            return SAT::Unsatisfiable;
+        }
+
+        let num_variables = self.formula.get_num_variables();
+        let mut edges: Vec<Vec<(usize, usize)>> = vec![Vec::new(); 2 * num_variables];
+        for (idx, clause) in self.formula.get_clauses().iter().enumerate() {
+            let first = clause.get_literal(0);
+            let second = clause.get_literal(clause.literals_len() - 1);
+            edges[Preprocessor::literal_node(-first)].push((Preprocessor::literal_node(second), idx));
+            if first != second {
+                edges[Preprocessor::literal_node(-second)].push((Preprocessor::literal_node(first), idx));
+            }
+        }
+        let components = Preprocessor::strongly_connected_components(&edges);
+
+        for variable in 1..=num_variables as isize {
+            let positive = components[Preprocessor::literal_node(variable)];
+            let negative = components[Preprocessor::literal_node(-variable)];
+            if positive == negative {
+                self.stats.increase_conflicts();
+                let negative_unit = self.derive_implication(&edges, variable, -variable);
+                let positive_unit = self.derive_implication(&edges, -variable, variable);
+                self.resolve(&negative_unit, &positive_unit, variable);
+                return SAT::Unsatisfiable;
+            }
+            // The components are numbered in reverse topological order.
+            self.model.add(if positive < negative { variable } else { -variable });
+        }
+
+        return SAT::Satisfiable;
+    }
+
+    /// Derive the clause of an implication between two literals, resolving the clauses along the shortest path of the implication graph.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `edges` - The implication graph, with the index of the clause of every edge.
+    /// * `from` - The implying literal.
+    /// * `to` - The implied literal.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Clause` - The derived clause, it contains the negation of the implying literal and the implied literal.
+    /// 
+    fn derive_implication(&mut self, edges: &Vec<Vec<(usize, usize)>>, from: isize, to: isize) -> Clause {
+        let from_node = Preprocessor::literal_node(from);
+        let to_node = Preprocessor::literal_node(to);
+
+        let mut parents: Vec<Option<(usize, usize)>> = vec![None; edges.len()];
+        let mut queue = VecDeque::from(vec![from_node]);
+        while let Some(node) = queue.pop_front() {
+            if node == to_node {
+                break;
+            }
+            for &(next, idx) in edges[node].iter() {
+                if next != from_node && parents[next].is_none() {
+                    parents[next] = Some((node, idx));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let mut path: Vec<(usize, usize)> = Vec::new();
+        let mut node = to_node;
+        while let Some((parent, idx)) = parents[node] {
+            path.push((node, idx));
+            node = parent;
+        }
+        path.reverse();
+
+        let mut clause = self.formula.get_clause(path[0].1).clone();
+        for window in path.windows(2) {
+            let pivot = Preprocessor::node_literal(window[0].0);
+            let next = self.formula.get_clause(window[1].1).clone();
+            clause = self.resolve(&next, &clause, pivot);
+        }
+        return clause;
+    }
+
+    /// Solve a Horn or dual-Horn formula with unit propagation, every clause is visited when one of its literals becomes false.
+    /// If there is no conflict, the unassigned variables are false for a Horn formula and true for a dual-Horn formula,
+    /// because every clause that is not satisfied has an unassigned literal with that value.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dual` - True if the formula is dual-Horn.
+    /// 
+    /// # Returns
+    /// 
+    /// * `SAT` - The result of the formula.
+    /// 
+    fn solve_horn(&mut self, dual: bool) -> SAT {
+
+        let num_variables = self.formula.get_num_variables();
+        let clauses = self.formula.get_clauses();
+        if clauses.iter().any(|clause| clause.literals_len() == 0) {
+            return SAT::Unsatisfiable;
+        }
+
+        let mut occurrences: Vec<Vec<usize>> = vec![Vec::new(); 2 * num_variables];
+        for (idx, clause) in clauses.iter().enumerate() {
+            for &literal in clause.iter_literals() {
+                occurrences[Preprocessor::literal_node(literal)].push(idx);
+            }
+        }
+        let mut not_false: Vec<usize> = clauses.iter().map(|clause| clause.literals_len()).collect();
+        let mut satisfied = vec![false; clauses.len()];
+        let mut reasons: Vec<usize> = vec![0; num_variables + 1];
+        let mut trail: Vec<isize> = Vec::new();
+        let mut units: Vec<(isize, usize)> = clauses.iter().enumerate()
+            .filter(|(_, clause)| clause.literals_len() == 1)
+            .map(|(idx, clause)| (clause.get_literal(0), idx))
+            .collect();
+
+        let mut conflict_idx: Option<usize> = None;
+        let mut head = 0;
+        'propagation: loop {
+            while let Some((literal, idx)) = units.pop() {
+                if self.model.has(literal) {
+                    continue;
+                }
+                if self.model.has(-literal) {
+                    conflict_idx = Some(idx);
+                    break 'propagation;
+                }
+                self.model.add(literal);
+                reasons[literal.unsigned_abs()] = idx;
+                trail.push(literal);
+                self.stats.increase_propagations();
+                occurrences[Preprocessor::literal_node(literal)].iter().for_each(|&idx| satisfied[idx] = true);
+            }
+            if head == trail.len() {
+                break;
+            }
+            let literal = trail[head];
+            head += 1;
+            for &idx in occurrences[Preprocessor::literal_node(-literal)].iter() {
+                not_false[idx] -= 1;
+                if satisfied[idx] {
+                    continue;
+                }
+                // The counters lag behind the assignment, so the last literal can be already false.
+                if not_false[idx] <= 1 {
+                    match clauses[idx].iter_literals().find(|&&x| !self.model.has(-x)) {
+                        Some(&unit) => units.push((unit, idx)),
+                        None => {
+                            conflict_idx = Some(idx);
+                            break 'propagation;
+                        },
+                    }
+                }
+            }
+        }
+
+        if let Some(conflict_idx) = conflict_idx {
+            // The conflict is explained by resolving the reasons of its literals, from the latest one.
+            self.stats.increase_conflicts();
+            let mut explanation = self.formula.get_clause(conflict_idx).clone();
+            for &literal in trail.iter().rev() {
+                if explanation.contains_literal(-literal) {
+                    let reason = self.formula.get_clause(reasons[literal.unsigned_abs()]).clone();
+                    explanation = self.resolve(&explanation, &reason, literal);
+                }
+            }
+            return SAT::Unsatisfiable;
+        }
+
+        for variable in 1..=num_variables as isize {
+            if !self.model.has_abs(variable as usize) {
+                self.model.add(if dual { variable } else { -variable });
+            }
+        }
+        return SAT::Satisfiable;
+    }
+
+}
\ No newline at end of file
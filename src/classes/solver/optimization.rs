@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+
+use crate::consts::{sat::SAT, encoding::{self, Comparator}, optimization::Optimization};
+use crate::classes::{formula::Formula, model::Model};
+use super::Solver;
+
+impl Solver {
+
+    /// Minimize the objective function of the formula.
+    /// The first model is found by `solve`, then the objective is bounded by a pseudo-Boolean constraint enabled by a
+    /// selector literal, and the formula is solved incrementally under the assumption of the selector.
+    /// The linear search bounds the objective below the best value found, the binary search bounds it below the middle
+    /// of the interval between the lower bound and the best value. A satisfiable bound is kept, an unsatisfiable one
+    /// raises the lower bound and it is disabled. Every improving value is printed as soon as it is found.
+    /// The formula is restored at the end and the learned clauses are discarded, they depend on the bounds.
+    /// A formula without clauses is satisfied by every assignment, so every term of the objective gets its lowest value.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(SAT, Option<isize>), ()>` - Satisfiable and the optimum if it is proved, unknown and the best value if a limit is reached,
+    /// an error if the formula has no objective function or if a model does not satisfy the bound of the objective.
+    ///
+    pub fn optimize(&mut self) -> Result<(SAT, Option<isize>), ()> {
+
+        let objective = match self.formula.get_objective() {
+            Some(objective) if objective.iter().all(|&(_, literal)| literal != 0) => objective.clone(),
+            _ => return Err(()),
+        };
+
+        let original_formula = self.formula.clone();
+        let result = self.search_optimum(&objective);
+        self.formula = original_formula;
+        self.learned_clauses = Vec::new();
+        self.xor_reasons = HashSet::new();
+        self.model.resize(self.formula.get_num_variables());
+
+        if let Ok((_, Some(value))) = result {
+            self.stats.set_objective(value);
+        }
+        return result;
+    }
+
+    /// Search the optimum of the objective function, the bounds are added to the formula.
+    ///
+    /// # Arguments
+    ///
+    /// * `objective` - The weight and the literal of every term of the objective function.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(SAT, Option<isize>), ()>` - The result of the formula and the best value of the objective function.
+    ///
+    fn search_optimum(&mut self, objective: &Vec<(isize, isize)>) -> Result<(SAT, Option<isize>), ()> {
+
+        if !self.is_formula_loaded() {
+            self.model = Model::new(Some(self.formula.get_num_variables()));
+            let optimum = self.complete_objective_model(objective);
+            println!("o {}", optimum);
+            return Ok((SAT::Satisfiable, Some(optimum)));
+        }
+
+        let sat = self.solve()?;
+        if sat != SAT::Satisfiable {
+            return Ok((sat, None));
+        }
+        let mut upper = self.complete_objective_model(objective);
+        let mut best_model = self.model.clone();
+        println!("o {}", upper);
+
+        // The objective function cannot be lower than the sum of its negative weights.
+        let mut lower: isize = objective.iter().map(|&(weight, _)| weight.min(0)).sum();
+        while lower < upper {
+            let bound = match self.optimization {
+                Optimization::Linear => upper - 1,
+                Optimization::Binary => lower + (upper - 1 - lower) / 2,
+            };
+
+            let selector = self.formula.new_auxiliary_variable("objective bound");
+            let encoding = self.encoding;
+            self.extend_formula(|formula| {
+                if formula.add_conditional_pseudo_boolean(selector, objective, Comparator::AtMost, bound, encoding).is_err() {
+                    let _ = formula.add_conditional_pseudo_boolean(selector, objective, Comparator::AtMost, bound, encoding::PSEUDO_BOOLEAN_FALLBACK);
+                }
+            });
+
+            match self.solve_with_assumptions(&vec![selector])? {
+                SAT::Satisfiable => {
+                    // The model satisfies the bound, otherwise the solver is wrong and the value is not an improvement.
+                    let value = self.complete_objective_model(objective);
+                    if value > bound {
+                        return Err(());
+                    }
+                    best_model = self.model.clone();
+                    upper = value;
+                    println!("o {}", upper);
+                    self.extend_formula(|formula| formula.add_clause(vec![selector]));
+                },
+                SAT::Unsatisfiable => {
+                    lower = bound + 1;
+                    self.extend_formula(|formula| formula.add_clause(vec![-selector]));
+                },
+                SAT::Unknown => {
+                    self.model = best_model;
+                    return Ok((SAT::Unknown, Some(upper)));
+                },
+            }
+        }
+
+        self.model = best_model;
+        return Ok((SAT::Satisfiable, Some(upper)));
+    }
+
+    /// Assigns the variables of the objective function that are not in the model, every term gets its lowest value.
+    /// The model satisfies every clause before the assignment, so it still satisfies them.
+    ///
+    /// # Arguments
+    ///
+    /// * `objective` - The weight and the literal of every term of the objective function.
+    ///
+    /// # Returns
+    ///
+    /// * `isize` - The value of the objective function in the model.
+    ///
+    fn complete_objective_model(&mut self, objective: &Vec<(isize, isize)>) -> isize {
+        for &(weight, literal) in objective.iter() {
+            if !self.model.has_abs(literal.unsigned_abs()) {
+                self.model.add(if weight > 0 { -literal } else { literal });
+            }
+        }
+        return self.formula.get_objective_value(&self.model).unwrap();
+    }
+
+    /// Add clauses to the formula between two incremental calls, their ids follow the ids of the learned clauses.
+    ///
+    /// # Arguments
+    ///
+    /// * `extend` - The function that adds the clauses to the formula.
+    ///
+    fn extend_formula(&mut self, extend: impl FnOnce(&mut Formula)) {
+        self.formula.set_current_clause_id(self.formula.get_current_clause_id().max(self.current_learned_clause_id));
+        extend(&mut self.formula);
+        self.current_learned_clause_id = self.formula.get_current_clause_id();
+    }
+
+}
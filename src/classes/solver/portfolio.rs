@@ -0,0 +1,230 @@
+use std::thread;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::consts::{sat::SAT, heuristics, portfolio};
+use crate::classes::{clause::Clause, model::Model, stats::Stats};
+use super::Solver;
+
+/// The learned clauses shared by the workers of a portfolio, every worker reads the clauses exported by the others.
+pub struct ClauseExchange {
+    clauses: Mutex<Vec<(usize, Vec<isize>, usize)>>,
+}
+
+/// The summary of a worker of a portfolio.
+pub struct Worker {
+    pub name: String,
+    pub result: Result<SAT, ()>,
+    pub time: Duration,
+    pub stats: Stats,
+}
+
+impl ClauseExchange {
+    pub fn new() -> ClauseExchange {
+        ClauseExchange {
+            clauses: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Add a learned clause to the exchange.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `worker` - The worker that learned the clause.
+    /// * `literals` - The literals of the clause.
+    /// * `lbd` - The LBD of the clause.
+    /// 
+    fn export(&self, worker: usize, literals: Vec<isize>, lbd: usize) {
+        if let Ok(mut clauses) = self.clauses.lock() {
+            clauses.push((worker, literals, lbd));
+        }
+    }
+
+    /// Returns the clauses exported by the other workers since the last call.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `worker` - The worker that imports the clauses.
+    /// * `cursor` - The number of clauses already read by the worker, it is moved to the end.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<(Vec<isize>, usize)>` - The literals and the LBD of each clause.
+    /// 
+    fn import(&self, worker: usize, cursor: &mut usize) -> Vec<(Vec<isize>, usize)> {
+        match self.clauses.lock() {
+            Ok(clauses) => {
+                let imported = clauses[*cursor..].iter()
+                    .filter(|(exporter, _, _)| *exporter != worker)
+                    .map(|(_, literals, lbd)| (literals.clone(), *lbd))
+                    .collect();
+                *cursor = clauses.len();
+                return imported;
+            },
+            Err(_) => return Vec::new(),
+        }
+    }
+}
+
+impl Solver {
+
+    /// Solve the formula with a portfolio of CDCL solvers on separate threads.
+    /// The first worker uses the options of this solver, the others use the next decision heuristics and a fraction of
+    /// random decisions with different seeds. The short and low-LBD learned clauses are shared between the workers, and
+    /// all the workers stop as soon as one of them finds the result.
+    /// The workers solve the original formula without preprocessing and without proofs, because the clauses learned on a
+    /// simplified formula cannot be shared and the shared clauses are not derived in the proof of the receiver.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<SAT, ()>` - The result of the formula, the statistics of the workers are summed.
+    /// 
+    pub(super) fn solve_portfolio(&mut self) -> Result<SAT, ()> {
+
+        let exchange = Arc::new(ClauseExchange::new());
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        let first_heuristic = heuristics::HEURISTICS.iter().position(|&name| name == self.heuristic.name()).unwrap_or(0);
+
+        let workers: Vec<(String, Solver)> = (0..self.portfolio_workers).map(|worker| {
+            let heuristic = heuristics::HEURISTICS[(first_heuristic + worker) % heuristics::HEURISTICS.len()];
+            let mut solver = self.fork(heuristic);
+            solver.exchange = Some((exchange.clone(), worker));
+            solver.limits.set_stop_flag(Some(stop_flag.clone()));
+            let name = match worker {
+                0 => heuristic.to_string(),
+                _ => {
+                    solver.set_random_decisions(portfolio::RANDOM_DECISION_FREQUENCY, worker as u64);
+                    format!("{}, seed {}", heuristic, worker)
+                },
+            };
+            (name, solver)
+        }).collect();
+
+        let results: Vec<(String, Solver, Result<SAT, ()>, Duration)> = thread::scope(|scope| {
+            let handles: Vec<_> = workers.into_iter().map(|(name, mut solver)| {
+                let stop_flag = stop_flag.clone();
+                scope.spawn(move || {
+                    let start = Instant::now();
+                    let result = solver.solve();
+                    if let Ok(SAT::Satisfiable | SAT::Unsatisfiable) = result {
+                        stop_flag.store(true, Ordering::SeqCst);
+                    }
+                    (name, solver, result, start.elapsed())
+                })
+            }).collect();
+            handles.into_iter().map(|handle| handle.join().expect("a worker of the portfolio panicked")).collect()
+        });
+
+        let mut stats = Stats::new();
+        results.iter().for_each(|(_, solver, _, _)| stats.merge(&solver.stats));
+
+        let winner = results.iter()
+            .filter(|(_, _, result, _)| matches!(result, Ok(SAT::Satisfiable | SAT::Unsatisfiable)))
+            .min_by_key(|(_, _, _, time)| *time)
+            .map(|(_, solver, result, _)| (solver.model.clone(), result.clone()));
+        let result = match winner {
+            Some((model, result)) => {
+                self.model = model;
+                result
+            },
+            None => {
+                if let Some(limit) = results.iter().find_map(|(_, solver, _, _)| solver.stats.get_limit_reached()) {
+                    stats.set_limit_reached(limit);
+                }
+                self.model = Model::new(Some(self.formula.get_num_variables()));
+                results.iter().map(|(_, _, result, _)| result.clone()).find(|result| result.is_err()).unwrap_or(Ok(SAT::Unknown))
+            },
+        };
+
+        self.stats = stats;
+        self.workers = results.into_iter().map(|(name, solver, result, time)| Worker {
+            name,
+            result,
+            time,
+            stats: solver.stats,
+        }).collect();
+
+        return result;
+    }
+
+    /// Creates a worker of the portfolio with the formula and the options of this solver.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `heuristic` - The decision heuristic of the worker.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Solver` - The worker.
+    /// 
+    pub(super) fn fork(&self, heuristic: &str) -> Solver {
+        let mut solver = Solver::new();
+        solver.formula = self.formula.clone();
+        let _ = solver.set_heuristic(heuristic);
+        let _ = solver.set_reduction_policy(self.reduction.name());
+        solver.set_reduction_first_limit(self.get_reduction_first_limit());
+        solver.set_reduction_growth(self.get_reduction_growth());
+        solver.set_reduction_fraction(self.get_reduction_fraction());
+        solver.set_minimization_enabled(self.minimization);
+        solver.set_xor_detection_enabled(self.xor_detection);
+        solver.set_timeout(self.get_timeout());
+        solver.set_conflict_limit(self.get_conflict_limit());
+        solver.set_decision_limit(self.get_decision_limit());
+        solver.set_memory_limit(self.get_memory_limit());
+        return solver;
+    }
+
+    /// Share a learned clause with the other workers of the portfolio, if it is short or its LBD is low.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `clause` - The learned clause.
+    /// 
+    pub(super) fn export_learned_clause(&mut self, clause: &Clause) {
+        if let Some((exchange, worker)) = &self.exchange {
+            if clause.literals_len() <= portfolio::SHARE_MAX_LENGTH || clause.get_lbd() <= portfolio::SHARE_MAX_LBD {
+                exchange.export(*worker, clause.iter_literals().cloned().collect(), clause.get_lbd());
+                self.stats.increase_clauses_exported();
+            }
+        }
+    }
+
+    /// Add the clauses learned by the other workers of the portfolio to the learned clauses.
+    /// The solver backjumps while an imported clause is falsified, a unit clause is propagated by the search.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<bool, ()>` - True if a clause was imported, an error if a clause is falsified at decision level 0, so the formula is unsatisfiable.
+    /// 
+    pub(super) fn import_shared_clauses(&mut self) -> Result<bool, ()> {
+        let imported = match &self.exchange {
+            Some((exchange, worker)) => exchange.import(*worker, &mut self.exchange_cursor),
+            None => return Ok(false),
+        };
+
+        let mut changed = false;
+        for (literals, lbd) in imported {
+            let mut clause = Clause::new();
+            clause.load_vec(literals);
+            let level = self.decision_level;
+            while self.decision_level > 0 && clause.iter_literals().all(|&literal| self.model.has(-literal)) {
+                self.backjump();
+            }
+            if level > self.decision_level {
+                self.stats.add_backjump(level - self.decision_level);
+            }
+            if clause.iter_literals().all(|&literal| self.model.has(-literal)) {
+                return Err(());
+            }
+            self.current_learned_clause_id += 1;
+            clause.set_id(self.current_learned_clause_id);
+            clause.set_lbd(lbd);
+            if self.add_learned_clause(clause) != 0 {
+                self.stats.increase_clauses_imported();
+                changed = true;
+            }
+        }
+        return Ok(changed);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,125 @@
+use std::time::Instant;
+
+use crate::consts::{sat::SAT, phase::Phase};
+use crate::classes::clause::Clause;
+use super::Solver;
+
+impl Solver {
+
+    /// Solve a formula with XOR constraints with an engine that only handles clauses, the constraints are encoded as clauses.
+    /// The new variables of the encoding are removed from the model.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<SAT, ()>` - The result of the formula.
+    /// 
+    pub(super) fn solve_encoded_xors(&mut self) -> Result<SAT, ()> {
+        let original_formula = self.formula.clone();
+        self.formula.encode_xors();
+
+        let result = self.solve();
+
+        self.model.resize(original_formula.get_num_variables());
+        self.formula = original_formula;
+        return result;
+    }
+
+    /// Propagate the XOR constraints with the Gauss-Jordan elimination, it is called when the unit propagation is complete.
+    /// Every implied literal and every conflict is explained by a clause implied by the constraints, the clause is learned
+    /// so that the search propagates it and the conflict analysis explains it like any other clause.
+    /// The clauses of the constraints are not derived by resolution, they are the axioms of the proof.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<bool, ()>` - True if a clause was learned, an error if the formula is unsatisfiable.
+    /// 
+    pub(super) fn propagate_xors(&mut self) -> Result<bool, ()> {
+        if self.gauss.is_empty() {
+            return Ok(false);
+        }
+
+        let gauss_start = Instant::now();
+        let propagated = self.gauss.propagate(&self.model);
+        self.stats.add_time(Phase::Gauss, gauss_start.elapsed());
+
+        match propagated {
+            Ok(reasons) => {
+                if reasons.is_empty() {
+                    return Ok(false);
+                }
+                for literals in reasons {
+                    self.learn_xor_clause(literals);
+                    self.stats.increase_xor_propagations();
+                }
+                // The watched literals of the new clauses are moved to their implied literals.
+                self.check_if_satisfied();
+                return Ok(true);
+            },
+            Err(literals) => {
+                self.stats.increase_xor_conflicts();
+                if literals.is_empty() {
+                    return Err(());
+                }
+                let conflict_clause_idx = self.learn_xor_clause(literals);
+                self.check_if_satisfied();
+
+                // The propagation was complete before the last literals were assigned, so one of them is in the conflict.
+                let conflict_clause = self.get_clause(conflict_clause_idx).clone();
+                let latest = self.decisions[self.decision_level].get_propagated_literals().iter().rev()
+                    .find(|&&(literal, _)| conflict_clause.contains_literal(-literal))
+                    .cloned();
+                match latest {
+                    Some((literal, clause_idx)) => {
+                        if !self.conflict_solver(literal, clause_idx, conflict_clause_idx) {
+                            return Err(());
+                        }
+                    },
+                    None => {
+                        let level = self.decision_level;
+                        while self.decision_level > 0 && conflict_clause.iter_literals().all(|&literal| self.model.has(-literal)) {
+                            self.backjump();
+                        }
+                        if level > self.decision_level {
+                            self.stats.add_backjump(level - self.decision_level);
+                        }
+                        if conflict_clause.iter_literals().all(|&literal| self.model.has(-literal)) {
+                            return Err(());
+                        }
+                    },
+                }
+                return Ok(true);
+            },
+        }
+    }
+
+    /// Learn a clause implied by the XOR constraints.
+    /// The clauses already learned from the constraints are indexed by their sorted literals, the learned clauses are only
+    /// searched when the clause was learned before, it can have been deleted since.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literals` - The literals of the clause.
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The index of the clause.
+    /// 
+    fn learn_xor_clause(&mut self, literals: Vec<isize>) -> usize {
+        let mut key = literals.clone();
+        key.sort();
+        let mut clause = Clause::new();
+        clause.load_vec(literals);
+        if !self.xor_reasons.insert(key) {
+            if let Some(idx) = self.learned_clauses.iter().position(|learned| *learned == clause) {
+                return self.formula.get_num_clauses() + idx;
+            }
+        }
+        self.current_learned_clause_id += 1;
+        clause.set_id(self.current_learned_clause_id);
+        clause.set_lbd(clause.literals_len());
+        self.tex_print_model("XOR", Some(format!("{}: {}", clause.get_id(), clause)));
+        self.learned_clauses.push(clause);
+        return self.formula.get_num_clauses() + self.learned_clauses.len() - 1;
+    }
+
+}
\ No newline at end of file
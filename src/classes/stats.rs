@@ -1,33 +1,165 @@
+use std::time::Duration;
+
 use byte_unit::Byte;
 use memory_stats::memory_stats;
 
+use crate::consts::phase::Phase;
+
 pub struct Stats {
+    decisions: usize,
+    propagations: usize,
+    conflicts: usize,
+    restarts: usize,
+    flips: usize,
+
+    backjumps: usize,
+    backjump_distance_total: usize,
+    backjump_distance_max: usize,
+
     clauses_learned: usize,
     clauses_forgotten: usize,
+    literals_minimized: usize,
+    learned_lengths: Vec<usize>,
+    learned_lbds: Vec<usize>,
+
+    phase_times: Vec<Duration>,
+
+    clauses_eliminated: usize,
+    variables_eliminated: usize,
+
+    clauses_exported: usize,
+    clauses_imported: usize,
+
+    cubes: usize,
+    cubes_refuted: usize,
+
+    xor_propagations: usize,
+    xor_conflicts: usize,
 
     virtual_memory: usize,
     physical_memory: usize,
+
+    backbone: Option<(usize, usize)>,
+    objective: Option<isize>,
+    limit_reached: Option<String>,
 }
 
 impl Stats {
     pub fn new() -> Stats {
         Stats {
+            decisions: 0,
+            propagations: 0,
+            conflicts: 0,
+            restarts: 0,
+            flips: 0,
+
+            backjumps: 0,
+            backjump_distance_total: 0,
+            backjump_distance_max: 0,
+
             clauses_learned: 0,
             clauses_forgotten: 0,
+            literals_minimized: 0,
+            learned_lengths: Vec::new(),
+            learned_lbds: Vec::new(),
+
+            phase_times: vec![Duration::ZERO; Phase::ALL.len()],
+
+            clauses_eliminated: 0,
+            variables_eliminated: 0,
+
+            clauses_exported: 0,
+            clauses_imported: 0,
+
+            cubes: 0,
+            cubes_refuted: 0,
+
+            xor_propagations: 0,
+            xor_conflicts: 0,
 
             virtual_memory: 0,
             physical_memory: 0,
+
+            backbone: None,
+            objective: None,
+            limit_reached: None,
         }
     }
 
-    /// Updates the memory usage
+    /// Updates the peak memory usage
     pub fn update(&mut self) {
         if let Some(usage) = memory_stats() {
-            self.virtual_memory = usage.virtual_mem;
-            self.physical_memory = usage.physical_mem;
+            self.virtual_memory = self.virtual_memory.max(usage.virtual_mem);
+            self.physical_memory = self.physical_memory.max(usage.physical_mem);
         };
     }
 
+    /// Increases the number of decisions
+    pub fn increase_decisions(&mut self) {
+        self.decisions += 1;
+    }
+
+    /// Increases the number of propagated literals
+    pub fn increase_propagations(&mut self) {
+        self.propagations += 1;
+    }
+
+    /// Increases the number of conflicts
+    pub fn increase_conflicts(&mut self) {
+        self.conflicts += 1;
+    }
+
+    /// Increases the number of restarts
+    pub fn increase_restarts(&mut self) {
+        self.restarts += 1;
+    }
+
+    /// Increases the number of flips of the local search
+    pub fn increase_flips(&mut self) {
+        self.flips += 1;
+    }
+
+    /// Adds a backjump
+    /// 
+    /// # Arguments
+    /// 
+    /// * `distance` - The number of decision levels undone by the backjump
+    /// 
+    pub fn add_backjump(&mut self, distance: usize) {
+        self.backjumps += 1;
+        self.backjump_distance_total += distance;
+        self.backjump_distance_max = self.backjump_distance_max.max(distance);
+    }
+
+    /// Adds a learned clause to the length and LBD distributions
+    /// 
+    /// # Arguments
+    /// 
+    /// * `length` - The number of literals of the clause
+    /// * `lbd` - The number of distinct decision levels of the literals of the clause
+    /// 
+    pub fn add_learned_clause(&mut self, length: usize, lbd: usize) {
+        if self.learned_lengths.len() <= length {
+            self.learned_lengths.resize(length + 1, 0);
+        }
+        self.learned_lengths[length] += 1;
+        if self.learned_lbds.len() <= lbd {
+            self.learned_lbds.resize(lbd + 1, 0);
+        }
+        self.learned_lbds[lbd] += 1;
+    }
+
+    /// Adds the time spent in a phase of the solver
+    /// 
+    /// # Arguments
+    /// 
+    /// * `phase` - The phase
+    /// * `duration` - The time spent
+    /// 
+    pub fn add_time(&mut self, phase: Phase, duration: Duration) {
+        self.phase_times[phase as usize] += duration;
+    }
+
     /// Increases the number of learned clauses
     pub fn increase_learned(&mut self) {
         self.clauses_learned += 1;
@@ -43,6 +175,521 @@ impl Stats {
         self.clauses_forgotten += amount;
     }
 
+    /// Increases the number of literals removed from the learned clauses by the minimization
+    /// 
+    /// # Arguments
+    /// 
+    /// * `amount` - The amount to increase
+    /// 
+    pub fn increase_literals_minimized(&mut self, amount: usize) {
+        self.literals_minimized += amount;
+    }
+
+    /// Increases the number of clauses eliminated by the preprocessing
+    /// 
+    /// # Arguments
+    /// 
+    /// * `amount` - The amount to increase
+    /// 
+    pub fn increase_clauses_eliminated(&mut self, amount: usize) {
+        self.clauses_eliminated += amount;
+    }
+
+    /// Increases the number of variables eliminated by the preprocessing
+    /// 
+    /// # Arguments
+    /// 
+    /// * `amount` - The amount to increase
+    /// 
+    pub fn increase_variables_eliminated(&mut self, amount: usize) {
+        self.variables_eliminated += amount;
+    }
+
+    /// Increases the number of learned clauses shared with the other solvers of a portfolio
+    pub fn increase_clauses_exported(&mut self) {
+        self.clauses_exported += 1;
+    }
+
+    /// Increases the number of clauses received from the other solvers of a portfolio
+    pub fn increase_clauses_imported(&mut self) {
+        self.clauses_imported += 1;
+    }
+
+    /// Increases the number of cubes of the split
+    /// 
+    /// # Arguments
+    /// 
+    /// * `amount` - The number of new cubes
+    /// 
+    pub fn increase_cubes(&mut self, amount: usize) {
+        self.cubes += amount;
+    }
+
+    /// Increases the number of cubes proved unsatisfiable by the conquer phase
+    pub fn increase_cubes_refuted(&mut self) {
+        self.cubes_refuted += 1;
+    }
+
+    /// Increases the number of literals implied by the Gauss-Jordan elimination of the XOR constraints
+    pub fn increase_xor_propagations(&mut self) {
+        self.xor_propagations += 1;
+    }
+
+    /// Increases the number of conflicts found by the Gauss-Jordan elimination of the XOR constraints
+    pub fn increase_xor_conflicts(&mut self) {
+        self.xor_conflicts += 1;
+    }
+
+    /// Adds the statistics of another solver, the counters and the times are summed and the memory peaks are kept
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The statistics to add
+    /// 
+    pub fn merge(&mut self, other: &Stats) {
+        self.decisions += other.decisions;
+        self.propagations += other.propagations;
+        self.conflicts += other.conflicts;
+        self.restarts += other.restarts;
+        self.flips += other.flips;
+
+        self.backjumps += other.backjumps;
+        self.backjump_distance_total += other.backjump_distance_total;
+        self.backjump_distance_max = self.backjump_distance_max.max(other.backjump_distance_max);
+
+        self.clauses_learned += other.clauses_learned;
+        self.clauses_forgotten += other.clauses_forgotten;
+        self.literals_minimized += other.literals_minimized;
+        for (histogram, other_histogram) in [(&mut self.learned_lengths, &other.learned_lengths), (&mut self.learned_lbds, &other.learned_lbds)] {
+            if histogram.len() < other_histogram.len() {
+                histogram.resize(other_histogram.len(), 0);
+            }
+            for (count, other_count) in histogram.iter_mut().zip(other_histogram.iter()) {
+                *count += other_count;
+            }
+        }
+
+        for phase in Phase::ALL {
+            self.phase_times[phase as usize] += other.phase_times[phase as usize];
+        }
+
+        self.clauses_eliminated += other.clauses_eliminated;
+        self.variables_eliminated += other.variables_eliminated;
+
+        self.clauses_exported += other.clauses_exported;
+        self.clauses_imported += other.clauses_imported;
+
+        self.cubes += other.cubes;
+        self.cubes_refuted += other.cubes_refuted;
+
+        self.xor_propagations += other.xor_propagations;
+        self.xor_conflicts += other.xor_conflicts;
+
+        self.virtual_memory = self.virtual_memory.max(other.virtual_memory);
+        self.physical_memory = self.physical_memory.max(other.physical_memory);
+    }
+
+    /// Sets the size of the computed backbone
+    /// 
+    /// # Arguments
+    /// 
+    /// * `backbone_size` - The number of literals in the backbone
+    /// * `num_variables` - The number of variables of the formula
+    /// 
+    pub fn set_backbone(&mut self, backbone_size: usize, num_variables: usize) {
+        self.backbone = Some((backbone_size, num_variables));
+    }
+
+    /// Returns the size of the computed backbone
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<(usize, usize)>` - The number of literals in the backbone and the number of variables, None if no backbone was computed
+    /// 
+    pub fn get_backbone(&self) -> Option<(usize, usize)> {
+        self.backbone
+    }
+
+    /// Sets the best value of the objective function found by the optimization
+    /// 
+    /// # Arguments
+    /// 
+    /// * `objective` - The value of the objective function
+    /// 
+    pub fn set_objective(&mut self, objective: isize) {
+        self.objective = Some(objective);
+    }
+
+    /// Returns the best value of the objective function found by the optimization
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<isize>` - The value of the objective function, None if no optimization was run
+    /// 
+    pub fn get_objective(&self) -> Option<isize> {
+        self.objective
+    }
+
+    /// Sets the reason that stopped the search
+    /// 
+    /// # Arguments
+    /// 
+    /// * `limit` - The reason, a reached limit or the user interruption
+    /// 
+    pub fn set_limit_reached(&mut self, limit: &str) {
+        self.limit_reached = Some(limit.to_string());
+    }
+
+    /// Returns the reason that stopped the search
+    /// 
+    /// # Returns
+    /// 
+    /// * `Option<&String>` - The reason, None if the search was not stopped
+    /// 
+    pub fn get_limit_reached(&self) -> Option<&String> {
+        self.limit_reached.as_ref()
+    }
+
+    /// Returns the number of decisions
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of decisions
+    /// 
+    pub fn get_decisions(&self) -> usize {
+        self.decisions
+    }
+
+    /// Returns the number of conflicts
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of conflicts
+    /// 
+    pub fn get_conflicts(&self) -> usize {
+        self.conflicts
+    }
+
+    /// Returns the number of propagated literals
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of propagated literals
+    /// 
+    pub fn get_propagations(&self) -> usize {
+        self.propagations
+    }
+
+    /// Returns the number of restarts
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of restarts
+    /// 
+    pub fn get_restarts(&self) -> usize {
+        self.restarts
+    }
+
+    /// Returns the number of flips of the local search
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of flips
+    /// 
+    pub fn get_flips(&self) -> usize {
+        self.flips
+    }
+
+    /// Returns the backjump distances
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of backjumps
+    /// * `f64` - The average number of decision levels undone by a backjump
+    /// * `usize` - The maximum number of decision levels undone by a backjump
+    /// 
+    pub fn get_backjumps(&self) -> (usize, f64, usize) {
+        (self.backjumps, Self::average(self.backjump_distance_total, self.backjumps), self.backjump_distance_max)
+    }
+
+    /// Returns the distribution of the lengths of the learned clauses
+    /// 
+    /// # Returns
+    /// 
+    /// * `&Vec<usize>` - The number of learned clauses for each length
+    /// 
+    pub fn get_learned_lengths(&self) -> &Vec<usize> {
+        &self.learned_lengths
+    }
+
+    /// Returns the distribution of the LBD of the learned clauses
+    /// 
+    /// # Returns
+    /// 
+    /// * `&Vec<usize>` - The number of learned clauses for each LBD
+    /// 
+    pub fn get_learned_lbds(&self) -> &Vec<usize> {
+        &self.learned_lbds
+    }
+
+    /// Returns the time spent in a phase of the solver
+    /// The propagation time is not measured directly, it is the search time not spent in the other phases
+    /// 
+    /// # Arguments
+    /// 
+    /// * `phase` - The phase
+    /// 
+    /// # Returns
+    /// 
+    /// * `Duration` - The time spent
+    /// 
+    pub fn get_time(&self, phase: Phase) -> Duration {
+        self.phase_times[phase as usize]
+    }
+
+    /// Returns the time spent propagating literals
+    /// 
+    /// # Returns
+    /// 
+    /// * `Duration` - The search time not spent in decisions, conflict analysis and inprocessing
+    /// 
+    pub fn get_propagation_time(&self) -> Duration {
+        self.get_time(Phase::Search)
+            .saturating_sub(self.get_time(Phase::Decision))
+            .saturating_sub(self.get_time(Phase::ConflictAnalysis))
+            .saturating_sub(self.get_time(Phase::Inprocessing))
+    }
+
+    /// Returns the number of events per second of search
+    /// 
+    /// # Arguments
+    /// 
+    /// * `count` - The number of events
+    /// 
+    /// # Returns
+    /// 
+    /// * `f64` - The rate, 0 if no time was spent searching
+    /// 
+    pub fn get_rate(&self, count: usize) -> f64 {
+        let seconds = self.get_time(Phase::Search).as_secs_f64();
+        if seconds == 0.0 {
+            return 0.0;
+        }
+        count as f64 / seconds
+    }
+
+    /// Summarizes a distribution in power of two buckets
+    /// 
+    /// # Arguments
+    /// 
+    /// * `distribution` - The number of occurrences of each value
+    /// 
+    /// # Returns
+    /// 
+    /// * `String` - The average, the maximum and the buckets of the distribution
+    /// 
+    pub fn format_distribution(distribution: &Vec<usize>) -> String {
+        let count: usize = distribution.iter().sum();
+        let total: usize = distribution.iter().enumerate().map(|(value, amount)| value * amount).sum();
+        let max = distribution.iter().rposition(|&amount| amount > 0).unwrap_or(0);
+
+        let mut buckets: Vec<String> = Vec::new();
+        let mut low = 1;
+        let mut high = 1;
+        while low <= max {
+            let amount: usize = distribution.iter().take(high + 1).skip(low).sum();
+            if amount > 0 {
+                match low == high {
+                    true => buckets.push(format!("{}: {}", low, amount)),
+                    false => buckets.push(format!("{}-{}: {}", low, high, amount)),
+                }
+            }
+            low = high + 1;
+            high = high * 2;
+        }
+
+        format!("avg {:.2}, max {} [{}]", Self::average(total, count), max, buckets.join(", "))
+    }
+
+    /// Serializes the statistics as a JSON object
+    /// 
+    /// # Arguments
+    /// 
+    /// * `metadata` - The fields of the instance to write before the statistics, with values already in JSON format
+    /// 
+    /// # Returns
+    /// 
+    /// * `String` - The JSON object
+    /// 
+    pub fn to_json(&self, metadata: &Vec<(&str, String)>) -> String {
+        let fields: Vec<String> = metadata.iter()
+            .map(|(key, value)| (key.to_string(), value.clone()))
+            .chain(self.get_fields())
+            .map(|(key, value)| format!("  {}: {}", Self::json_string(&key), value))
+            .collect();
+        format!("{{\n{}\n}}\n", fields.join(",\n"))
+    }
+
+    /// Serializes the statistics as a CSV row
+    /// 
+    /// # Arguments
+    /// 
+    /// * `metadata` - The fields of the instance to write before the statistics, with values already in JSON format
+    /// * `header` - True to write the header row before the values
+    /// 
+    /// # Returns
+    /// 
+    /// * `String` - The CSV rows
+    /// 
+    pub fn to_csv(&self, metadata: &Vec<(&str, String)>, header: bool) -> String {
+        let fields: Vec<(String, String)> = metadata.iter()
+            .map(|(key, value)| (key.to_string(), value.clone()))
+            .chain(self.get_fields())
+            .collect();
+
+        let mut csv = String::new();
+        if header {
+            csv.push_str(&fields.iter().map(|(key, _)| key.clone()).collect::<Vec<String>>().join(","));
+            csv.push('\n');
+        }
+        let values: Vec<String> = fields.iter().map(|(_, value)| {
+            if !value.starts_with('"') {
+                return Self::csv_string(value);
+            }
+            // The JSON escapes are removed one character at a time, so an escaped backslash is not read as an escape.
+            let mut unescaped = String::new();
+            let mut characters = value[1..value.len() - 1].chars();
+            while let Some(character) = characters.next() {
+                match character {
+                    '\\' => match characters.next() {
+                        Some('n') => unescaped.push('\n'),
+                        Some(escaped) => unescaped.push(escaped),
+                        None => (),
+                    },
+                    _ => unescaped.push(character),
+                }
+            }
+            Self::csv_string(&unescaped)
+        }).collect();
+        csv.push_str(&values.join(","));
+        csv.push('\n');
+        return csv;
+    }
+
+    /// Formats a string as a JSON string
+    /// 
+    /// # Arguments
+    /// 
+    /// * `value` - The string
+    /// 
+    /// # Returns
+    /// 
+    /// * `String` - The quoted and escaped string
+    /// 
+    pub fn json_string(value: &str) -> String {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+    }
+
+    /// Formats a string as a CSV field
+    /// 
+    /// # Arguments
+    /// 
+    /// * `value` - The string
+    /// 
+    /// # Returns
+    /// 
+    /// * `String` - The string, quoted with the quotes doubled if it has a comma, a quote or a newline
+    /// 
+    pub fn csv_string(value: &str) -> String {
+        match value.contains(',') || value.contains('"') || value.contains('\n') {
+            true => format!("\"{}\"", value.replace('"', "\"\"")),
+            false => value.to_string(),
+        }
+    }
+
+    /// Returns all the statistics as fields
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<(String, String)>` - The name and the value in JSON format of each statistic
+    /// 
+    fn get_fields(&self) -> Vec<(String, String)> {
+        let (backjumps, average_distance, max_distance) = self.get_backjumps();
+        let json_array = |values: &Vec<usize>| format!("[{}]", values.iter().map(|value| value.to_string()).collect::<Vec<String>>().join(","));
+
+        let mut fields: Vec<(String, String)> = vec![
+            ("decisions", self.decisions.to_string()),
+            ("propagations", self.propagations.to_string()),
+            ("conflicts", self.conflicts.to_string()),
+            ("restarts", self.restarts.to_string()),
+            ("flips", self.flips.to_string()),
+            ("backjumps", backjumps.to_string()),
+            ("backjump_distance_avg", format!("{:.4}", average_distance)),
+            ("backjump_distance_max", max_distance.to_string()),
+            ("clauses_learned", self.clauses_learned.to_string()),
+            ("clauses_forgotten", self.clauses_forgotten.to_string()),
+            ("literals_minimized", self.literals_minimized.to_string()),
+            ("learned_lengths", json_array(&self.learned_lengths)),
+            ("learned_lbds", json_array(&self.learned_lbds)),
+            ("clauses_eliminated", self.clauses_eliminated.to_string()),
+            ("variables_eliminated", self.variables_eliminated.to_string()),
+            ("clauses_exported", self.clauses_exported.to_string()),
+            ("clauses_imported", self.clauses_imported.to_string()),
+            ("cubes", self.cubes.to_string()),
+            ("cubes_refuted", self.cubes_refuted.to_string()),
+            ("xor_propagations", self.xor_propagations.to_string()),
+            ("xor_conflicts", self.xor_conflicts.to_string()),
+        ].into_iter().map(|(key, value)| (key.to_string(), value)).collect();
+
+        for phase in Phase::ALL {
+            fields.push((
+                format!("time_{}", phase.to_string().to_lowercase().replace(' ', "_")),
+                format!("{:.6}", self.get_time(phase).as_secs_f64())
+            ));
+        }
+
+        fields.extend(vec![
+            ("time_propagation", format!("{:.6}", self.get_propagation_time().as_secs_f64())),
+            ("decisions_per_second", format!("{:.2}", self.get_rate(self.decisions))),
+            ("propagations_per_second", format!("{:.2}", self.get_rate(self.propagations))),
+            ("conflicts_per_second", format!("{:.2}", self.get_rate(self.conflicts))),
+            ("peak_virtual_memory", self.virtual_memory.to_string()),
+            ("peak_physical_memory", self.physical_memory.to_string()),
+            ("backbone_size", match self.backbone {
+                Some((backbone_size, _)) => backbone_size.to_string(),
+                None => "null".to_string(),
+            }),
+            ("objective", match self.objective {
+                Some(objective) => objective.to_string(),
+                None => "null".to_string(),
+            }),
+            ("limit_reached", match &self.limit_reached {
+                Some(limit) => Self::json_string(limit),
+                None => "null".to_string(),
+            }),
+        ].into_iter().map(|(key, value)| (key.to_string(), value)));
+
+        return fields;
+    }
+
+    /// Divides two counters
+    /// 
+    /// # Arguments
+    /// 
+    /// * `total` - The dividend
+    /// * `count` - The divisor
+    /// 
+    /// # Returns
+    /// 
+    /// * `f64` - The average, 0 if the divisor is 0
+    /// 
+    fn average(total: usize, count: usize) -> f64 {
+        if count == 0 {
+            return 0.0;
+        }
+        total as f64 / count as f64
+    }
+
     /// Returns the number of learned clauses
     /// 
     /// # Returns
@@ -63,11 +710,101 @@ impl Stats {
         self.clauses_forgotten
     }
 
-    /// Returns the virtual memory usage
+    /// Returns the number of literals removed from the learned clauses by the minimization
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of removed literals
+    /// 
+    pub fn get_literals_minimized(&self) -> usize {
+        self.literals_minimized
+    }
+
+    /// Returns the number of clauses eliminated by the preprocessing
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of eliminated clauses
+    /// 
+    pub fn get_clauses_eliminated(&self) -> usize {
+        self.clauses_eliminated
+    }
+
+    /// Returns the number of variables eliminated by the preprocessing
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of eliminated variables
+    /// 
+    pub fn get_variables_eliminated(&self) -> usize {
+        self.variables_eliminated
+    }
+
+    /// Returns the number of learned clauses shared with the other solvers of a portfolio
     /// 
     /// # Returns
     /// 
-    /// * `String` - The virtual memory usage
+    /// * `usize` - The number of exported clauses
+    /// 
+    pub fn get_clauses_exported(&self) -> usize {
+        self.clauses_exported
+    }
+
+    /// Returns the number of clauses received from the other solvers of a portfolio
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of imported clauses
+    /// 
+    pub fn get_clauses_imported(&self) -> usize {
+        self.clauses_imported
+    }
+
+    /// Returns the number of cubes of the split
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of cubes
+    /// 
+    pub fn get_cubes(&self) -> usize {
+        self.cubes
+    }
+
+    /// Returns the number of cubes proved unsatisfiable by the conquer phase
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of refuted cubes
+    /// 
+    pub fn get_cubes_refuted(&self) -> usize {
+        self.cubes_refuted
+    }
+
+    /// Returns the number of literals implied by the XOR constraints
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of XOR propagations
+    /// 
+    pub fn get_xor_propagations(&self) -> usize {
+        self.xor_propagations
+    }
+
+    /// Returns the number of conflicts found by the XOR constraints
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of XOR conflicts
+    /// 
+    pub fn get_xor_conflicts(&self) -> usize {
+        self.xor_conflicts
+    }
+
+    /// Returns the peak virtual memory usage
+    /// 
+    /// # Returns
+    /// 
+    /// * `String` - The peak virtual memory usage
     /// 
     pub fn get_virtual_memory(&self) -> String {
         //self.virtual_memory
@@ -75,11 +812,21 @@ impl Stats {
         format!("{byte:#}")
     }
 
-    /// Returns the physical memory usage
+    /// Returns the peak physical memory usage in bytes
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The peak physical memory usage
+    /// 
+    pub fn get_physical_memory_bytes(&self) -> usize {
+        self.physical_memory
+    }
+
+    /// Returns the peak physical memory usage
     /// 
     /// # Returns
     /// 
-    /// * `String` - The physical memory usage
+    /// * `String` - The peak physical memory usage
     /// 
     pub fn get_physical_memory(&self) -> String {
         //self.physical_memory
@@ -0,0 +1,150 @@
+use std::fmt;
+
+use crate::consts::sat::SAT;
+use crate::classes::model::Model;
+
+#[derive(Clone, PartialEq)]
+pub struct Xor {
+    variables: Vec<usize>,
+    parity: bool,
+}
+
+impl Xor {
+    pub fn new() -> Xor {
+        Xor {
+            variables: Vec::new(),
+            parity: true,
+        }
+    }
+
+    /// Load a XOR constraint from a string in the CryptoMiniSat format, "x1 -2 3 0" is x1 ⊕ ¬x2 ⊕ x3
+    /// 
+    /// # Arguments
+    /// 
+    /// * `xor_string` - The string to load, it starts with 'x'
+    /// 
+    /// # Returns
+    /// 
+    /// * `Result<(), ()>` - Err(()) if the string is not a XOR constraint
+    /// 
+    pub fn load_string(&mut self, xor_string: String) -> Result<(), ()> {
+        let literals = match xor_string.trim().strip_prefix('x') {
+            Some(literals) => literals,
+            None => return Err(()),
+        };
+        let literals: Result<Vec<isize>, _> = literals.split_whitespace().map(|literal| literal.parse::<isize>()).collect();
+        match literals {
+            Ok(literals) => {
+                self.load_vec(literals.into_iter().filter(|&literal| literal != 0).collect());
+                Ok(())
+            },
+            Err(_) => Err(()),
+        }
+    }
+
+    /// Load the literals of a XOR constraint, the constraint is true if an odd number of literals is true
+    /// A negative literal flips the parity, and a variable that appears twice cancels out
+    /// 
+    /// # Arguments
+    /// 
+    /// * `literals` - The literals
+    /// 
+    pub fn load_vec(&mut self, literals: Vec<isize>) {
+        self.parity = literals.iter().filter(|&&literal| literal < 0).count() % 2 == 0;
+        let mut variables: Vec<usize> = literals.iter().map(|literal| literal.unsigned_abs()).collect();
+        variables.sort();
+        self.variables = Vec::new();
+        for variable in variables {
+            if self.variables.last() == Some(&variable) {
+                self.variables.pop();
+            } else {
+                self.variables.push(variable);
+            }
+        }
+    }
+
+    /// Returns the variables of the constraint
+    /// 
+    /// # Returns
+    /// 
+    /// * `&Vec<usize>` - The variables, in increasing order
+    /// 
+    pub fn get_variables(&self) -> &Vec<usize> {
+        &self.variables
+    }
+
+    /// Returns the parity of the constraint
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the number of true variables must be odd, false if it must be even
+    /// 
+    pub fn get_parity(&self) -> bool {
+        self.parity
+    }
+
+    /// Sets the parity of the constraint
+    /// 
+    /// # Arguments
+    /// 
+    /// * `parity` - True if the number of true variables must be odd, false if it must be even
+    /// 
+    pub fn set_parity(&mut self, parity: bool) {
+        self.parity = parity;
+    }
+
+    /// Check if the constraint is satisfied by a model
+    /// 
+    /// # Arguments
+    /// 
+    /// * `model` - The model to check
+    /// 
+    /// # Returns
+    /// 
+    /// * `SAT` - Unknown if a variable is not assigned
+    /// 
+    pub fn is_satisfied_by_model(&self, model: &Model) -> SAT {
+        let mut parity = false;
+        for &variable in self.variables.iter() {
+            match model.satisfies(variable as isize) {
+                SAT::Satisfiable => parity = !parity,
+                SAT::Unsatisfiable => (),
+                SAT::Unknown => return SAT::Unknown,
+            }
+        }
+        if parity == self.parity { SAT::Satisfiable } else { SAT::Unsatisfiable }
+    }
+
+    /// Returns the clauses of the direct encoding, one clause for each assignment with the wrong parity
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<Vec<isize>>` - The 2^(n-1) clauses of a constraint of n variables
+    /// 
+    pub fn to_clauses(&self) -> Vec<Vec<isize>> {
+        let mut clauses: Vec<Vec<isize>> = Vec::new();
+        for signs in 0..(1usize << self.variables.len()) {
+            // A clause forbids the assignment that falsifies all its literals, so the negative literals are the true variables.
+            if (signs.count_ones() % 2 == 0) != self.parity {
+                continue;
+            }
+            clauses.push(self.variables.iter().enumerate()
+                .map(|(idx, &variable)| if signs >> idx & 1 == 1 { -(variable as isize) } else { variable as isize })
+                .collect());
+        }
+        return clauses;
+    }
+}
+
+impl fmt::Display for Xor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let literals: Vec<String> = self.variables.iter().enumerate()
+            .map(|(idx, &variable)| if idx == 0 && !self.parity { format!("-{}", variable) } else { variable.to_string() })
+            .collect();
+        if self.variables.is_empty() && !self.parity {
+            // An empty constraint of even parity is always true, it is written as x1 ⊕ ¬x1.
+            return write!(f, "x1 -1 0");
+        }
+        write!(f, "x{} 0", literals.join(" "))
+    }
+}
\ No newline at end of file
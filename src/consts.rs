@@ -1,3 +1,17 @@
 pub mod sat;
 pub mod operators;
-pub mod editor_types;
\ No newline at end of file
+pub mod editor_types;
+pub mod preprocessing;
+pub mod limits;
+pub mod phase;
+pub mod progress;
+pub mod heuristics;
+pub mod reduction;
+pub mod engine;
+pub mod local_search;
+pub mod portfolio;
+pub mod cube;
+pub mod fragment;
+pub mod xor;
+pub mod encoding;
+pub mod optimization;
\ No newline at end of file
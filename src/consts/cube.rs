@@ -0,0 +1,2 @@
+pub const DEFAULT_DEPTH: usize = 8;
+pub const DEPTH_CHOICES: [usize; 5] = [4, 6, 8, 10, 12];
\ No newline at end of file
@@ -0,0 +1,74 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Encoding {
+    Pairwise,
+    SequentialCounter,
+    Totalizer,
+    CardinalityNetwork,
+    Adder,
+    Bdd,
+}
+
+impl Encoding {
+    pub const ALL: [Encoding; 6] = [
+        Encoding::Pairwise,
+        Encoding::SequentialCounter,
+        Encoding::Totalizer,
+        Encoding::CardinalityNetwork,
+        Encoding::Adder,
+        Encoding::Bdd,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Encoding> {
+        match name.to_lowercase().as_str() {
+            "pairwise" => Some(Encoding::Pairwise),
+            "seq" | "sequential" => Some(Encoding::SequentialCounter),
+            "totalizer" => Some(Encoding::Totalizer),
+            "network" | "cardinality-network" => Some(Encoding::CardinalityNetwork),
+            "adder" => Some(Encoding::Adder),
+            "bdd" => Some(Encoding::Bdd),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the encoding handles the constraints with different weights, the others only handle cardinality constraints.
+    pub fn is_pseudo_boolean(&self) -> bool {
+        matches!(self, Encoding::Adder | Encoding::Bdd)
+    }
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Pairwise => write!(f, "Pairwise"),
+            Self::SequentialCounter => write!(f, "Sequential counter"),
+            Self::Totalizer => write!(f, "Totalizer"),
+            Self::CardinalityNetwork => write!(f, "Cardinality network"),
+            Self::Adder => write!(f, "Adder"),
+            Self::Bdd => write!(f, "BDD"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparator {
+    AtMost,
+    AtLeast,
+    Exactly,
+}
+
+impl fmt::Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::AtMost => write!(f, "<="),
+            Self::AtLeast => write!(f, ">="),
+            Self::Exactly => write!(f, "="),
+        }
+    }
+}
+
+/// The encoding of the constraints when no encoding is selected.
+pub const DEFAULT_ENCODING: Encoding = Encoding::Totalizer;
+/// The encoding of the constraints with different weights when the selected encoding only handles cardinality constraints.
+pub const PSEUDO_BOOLEAN_FALLBACK: Encoding = Encoding::Bdd;
@@ -0,0 +1,47 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Engine {
+    Cdcl,
+    Dpll,
+    DavisPutnam,
+    WalkSat,
+    ProbSat,
+    CubeAndConquer,
+}
+
+impl Engine {
+    pub const ALL: [Engine; 6] = [
+        Engine::Cdcl,
+        Engine::Dpll,
+        Engine::DavisPutnam,
+        Engine::WalkSat,
+        Engine::ProbSat,
+        Engine::CubeAndConquer,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Engine> {
+        match name.to_lowercase().as_str() {
+            "cdcl" => Some(Engine::Cdcl),
+            "dpll" => Some(Engine::Dpll),
+            "dp" | "davis-putnam" => Some(Engine::DavisPutnam),
+            "walksat" => Some(Engine::WalkSat),
+            "probsat" => Some(Engine::ProbSat),
+            "cnc" | "cube-and-conquer" => Some(Engine::CubeAndConquer),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Engine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Cdcl => write!(f, "CDCL"),
+            Self::Dpll => write!(f, "DPLL"),
+            Self::DavisPutnam => write!(f, "Davis-Putnam"),
+            Self::WalkSat => write!(f, "WalkSAT"),
+            Self::ProbSat => write!(f, "ProbSAT"),
+            Self::CubeAndConquer => write!(f, "Cube-and-conquer"),
+        }
+    }
+}
\ No newline at end of file
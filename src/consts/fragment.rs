@@ -0,0 +1,20 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Fragment {
+    TwoCnf,
+    Horn,
+    DualHorn,
+    General,
+}
+
+impl fmt::Display for Fragment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::TwoCnf => write!(f, "2-CNF"),
+            Self::Horn => write!(f, "Horn"),
+            Self::DualHorn => write!(f, "dual-Horn"),
+            Self::General => write!(f, "general"),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+pub const HEURISTICS: [&str; 6] = ["VSIDS", "VMTF", "CHB", "JW", "DLIS", "MOMs"];
+pub const DEFAULT_HEURISTIC: &str = "VSIDS";
+pub const CHB_STEP_START: f64 = 0.4;
+pub const CHB_STEP_MIN: f64 = 0.06;
+pub const CHB_STEP_DECAY: f64 = 0.000001;
+pub const MOMS_WEIGHT: usize = 1024;
\ No newline at end of file
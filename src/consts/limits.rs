@@ -0,0 +1,5 @@
+pub const TIMEOUT_CHOICES: [usize; 4] = [10, 60, 300, 3600];
+pub const CONFLICT_LIMIT_CHOICES: [usize; 4] = [1000, 10000, 100000, 1000000];
+pub const DECISION_LIMIT_CHOICES: [usize; 4] = [1000, 10000, 100000, 1000000];
+pub const MEMORY_LIMIT_CHOICES: [usize; 4] = [256, 1024, 4096, 16384];
+pub const BENCHMARK_TIMEOUT: usize = 60;
\ No newline at end of file
@@ -0,0 +1,6 @@
+pub const DEFAULT_NOISE: f64 = 0.567;
+pub const DEFAULT_CB: f64 = 2.06;
+pub const PROBSAT_EPS: f64 = 1.0;
+pub const DEFAULT_MAX_FLIPS: usize = 1000000;
+pub const DEFAULT_MAX_RESTARTS: usize = 10;
+pub const LIMIT_CHECK_INTERVAL: usize = 1024;
\ No newline at end of file
@@ -0,0 +1,31 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Optimization {
+    Linear,
+    Binary,
+}
+
+impl Optimization {
+    pub const ALL: [Optimization; 2] = [
+        Optimization::Linear,
+        Optimization::Binary,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Optimization> {
+        match name.to_lowercase().as_str() {
+            "linear" => Some(Optimization::Linear),
+            "binary" => Some(Optimization::Binary),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Optimization {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Linear => write!(f, "Linear search"),
+            Self::Binary => write!(f, "Binary search"),
+        }
+    }
+}
\ No newline at end of file
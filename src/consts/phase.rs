@@ -0,0 +1,38 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Phase {
+    Preprocessing,
+    Inprocessing,
+    Search,
+    Decision,
+    ConflictAnalysis,
+    Lookahead,
+    Gauss,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 7] = [
+        Phase::Preprocessing,
+        Phase::Inprocessing,
+        Phase::Search,
+        Phase::Decision,
+        Phase::ConflictAnalysis,
+        Phase::Lookahead,
+        Phase::Gauss,
+    ];
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Preprocessing => write!(f, "Preprocessing"),
+            Self::Inprocessing => write!(f, "Inprocessing"),
+            Self::Search => write!(f, "Search"),
+            Self::Decision => write!(f, "Decision"),
+            Self::ConflictAnalysis => write!(f, "Conflict analysis"),
+            Self::Lookahead => write!(f, "Lookahead"),
+            Self::Gauss => write!(f, "Gauss elimination"),
+        }
+    }
+}
\ No newline at end of file
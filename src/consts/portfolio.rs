@@ -0,0 +1,4 @@
+pub const SHARE_MAX_LENGTH: usize = 8;
+pub const SHARE_MAX_LBD: usize = 2;
+pub const RANDOM_DECISION_FREQUENCY: f64 = 0.02;
+pub const WORKER_CHOICES: [usize; 5] = [1, 2, 4, 8, 16];
\ No newline at end of file
@@ -0,0 +1,4 @@
+pub const MAX_ELIMINATION_OCCURRENCES: usize = 16;
+pub const MAX_RESOLVENT_LENGTH: usize = 20;
+pub const MAX_COVERED_CLAUSE_LENGTH: usize = 40;
+pub const INPROCESSING_INTERVAL: usize = 1000;
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub const CONFLICT_INTERVAL_CHOICES: [usize; 3] = [100, 1000, 10000];
+pub const TIME_INTERVAL_CHOICES: [usize; 3] = [1, 5, 30];
\ No newline at end of file
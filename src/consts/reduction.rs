@@ -0,0 +1,6 @@
+pub const POLICIES: [&str; 5] = ["Length", "Activity", "LBD", "Age", "Keep all"];
+pub const DEFAULT_POLICY: &str = "Length";
+pub const DEFAULT_GROWTH: f64 = 1.5;
+pub const DEFAULT_FRACTION: f64 = 0.5;
+pub const ACTIVITY_DECAY: f64 = 0.95;
+pub const GLUE_LBD: usize = 2;
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub const MIN_DETECTED_SIZE: usize = 3;
+pub const MAX_DETECTED_SIZE: usize = 6;
+pub const ENCODING_CUT: usize = 4;
\ No newline at end of file
@@ -1,4 +1,5 @@
 use std::fs;
+use std::io::Write;
 
 /// Reads a file
 /// 
@@ -27,4 +28,74 @@ pub fn read_file(path: &str) -> Result<String, std::io::Error> {
 /// 
 pub fn file_exists(path: &str) -> bool {
     return fs::metadata(path).is_ok();
+}
+
+/// Writes a file, replacing its content
+/// 
+/// # Arguments
+/// 
+/// * `path` - The path of the file
+/// * `contents` - The content to write
+/// 
+/// # Returns
+/// 
+/// * `Result<(), std::io::Error>` - The result of the operation
+/// 
+pub fn write_file(path: &str, contents: &str) -> Result<(), std::io::Error> {
+    fs::write(path, contents)?;
+    return Ok(());
+}
+
+/// Appends to a file, creating it if it does not exist
+/// 
+/// # Arguments
+/// 
+/// * `path` - The path of the file
+/// * `contents` - The content to append
+/// 
+/// # Returns
+/// 
+/// * `Result<(), std::io::Error>` - The result of the operation
+/// 
+pub fn append_file(path: &str, contents: &str) -> Result<(), std::io::Error> {
+    let mut file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(contents.as_bytes())?;
+    return Ok(());
+}
+
+/// Lists the files of a directory with an extension
+/// 
+/// # Arguments
+/// 
+/// * `path` - The path of the directory
+/// * `extension` - The extension of the files, without the dot
+/// 
+/// # Returns
+/// 
+/// * `Result<Vec<String>, std::io::Error>` - The sorted paths of the files
+/// 
+pub fn list_files(path: &str, extension: &str) -> Result<Vec<String>, std::io::Error> {
+    let mut paths: Vec<String> = Vec::new();
+    for entry in fs::read_dir(path)? {
+        let entry_path = entry?.path();
+        if entry_path.is_file() && entry_path.extension().map_or(false, |entry_extension| entry_extension == extension) {
+            paths.push(entry_path.to_string_lossy().to_string());
+        }
+    }
+    paths.sort();
+    return Ok(paths);
+}
+/// Creates a directory and its parents, if they do not exist
+/// 
+/// # Arguments
+/// 
+/// * `path` - The path of the directory
+/// 
+/// # Returns
+/// 
+/// * `Result<(), std::io::Error>` - The result of the operation
+/// 
+pub fn create_directory(path: &str) -> Result<(), std::io::Error> {
+    fs::create_dir_all(path)?;
+    return Ok(());
 }
\ No newline at end of file
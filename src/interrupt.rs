@@ -0,0 +1,42 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static SOLVING: AtomicBool = AtomicBool::new(false);
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Installs the Ctrl-C handler
+/// While the solver is running Ctrl-C stops the search, otherwise it exits the program
+/// 
+/// # Returns
+/// 
+/// * `Result<(), ctrlc::Error>` - The result of the operation
+/// 
+pub fn init() -> Result<(), ctrlc::Error> {
+    ctrlc::set_handler(|| {
+        if SOLVING.load(Ordering::SeqCst) {
+            INTERRUPTED.store(true, Ordering::SeqCst);
+        } else {
+            std::process::exit(130);
+        }
+    })
+}
+
+/// Marks the start of a search, clearing any previous interruption
+pub fn start_solving() {
+    INTERRUPTED.store(false, Ordering::SeqCst);
+    SOLVING.store(true, Ordering::SeqCst);
+}
+
+/// Marks the end of a search
+pub fn stop_solving() {
+    SOLVING.store(false, Ordering::SeqCst);
+}
+
+/// Checks if the search was interrupted by the user
+/// 
+/// # Returns
+/// 
+/// * `bool` - true if Ctrl-C was pressed during the search, false otherwise
+/// 
+pub fn is_interrupted() -> bool {
+    return INTERRUPTED.load(Ordering::SeqCst);
+}
\ No newline at end of file
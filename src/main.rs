@@ -1,21 +1,28 @@
 pub mod input;
 pub mod files;
+pub mod interrupt;
 pub mod tools;
 
 pub mod consts;
 pub mod classes;
 
 use std::vec;
-use std::time::Instant;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 pub use crate::classes::solver::Solver;
-pub use crate::consts::{sat::SAT, editor_types::EditorTypes};
+pub use crate::tools::{benchmark, fuzzer, generators};
+pub use crate::consts::{sat::SAT, editor_types::EditorTypes, encoding::Encoding, engine::Engine, optimization::Optimization, cube, heuristics, limits, portfolio, progress, reduction};
 
 
 fn main() {
     
     let mut solver = Solver::new();
 
+    if let Err(e) = interrupt::init() {
+        eprintln!("Error setting the Ctrl-C handler: {:?}", e);
+    }
+
     println!("");
     println!("        SAT  Solver        ");
     println!("A program by Matteo Ingusci");
@@ -26,13 +33,36 @@ fn main() {
 
     let args: Vec<String> = std::env::args().collect();
     if args.len() > 1 {
+        let mut backbone_mode = false;
+        let mut file_name = String::new();
+        let mut opb_file: Option<String> = None;
+        let mut stats_json: Option<String> = None;
+        let mut stats_csv: Option<String> = None;
+        let mut bench_directory: Option<String> = None;
+        let mut bench_results = String::from("bench_results.csv");
+        let mut compare_files: Option<(String, String)> = None;
+        let mut cactus_file = String::from("cactus.dat");
+        let mut generate_kind: Option<String> = None;
+        let mut generate_parameters: HashMap<String, String> = HashMap::new();
+        let mut generate_output: Option<String> = None;
+        let mut fuzz_iterations: Option<usize> = None;
+        let mut fuzz_seed: u64 = 0;
+        let mut fuzz_directory = String::from("fuzz_failures");
+        let mut icnf_output: Option<String> = None;
+        let mut args_iter = args.iter().skip(1);
         //check if an argument is a file
-        for arg in args.iter().skip(1) {
+        while let Some(arg) = args_iter.next() {
             if files::file_exists(arg) && arg.ends_with(".cnf") {
                 match solver.formula.load_file(arg) {
-                    Ok(()) => println!("File loaded successfully!"),
+                    Ok(()) => {
+                        file_name = arg.clone();
+                        println!("File loaded successfully!")
+                    },
                     Err(e) => eprintln!("Error loading file: {:?}", e),
                 }
+            } //else if is an OPB file, it is loaded after the encoding is selected
+            else if files::file_exists(arg) && arg.ends_with(".opb") {
+                opb_file = Some(arg.clone());
             } //else if is "-dot"
             else if arg == "-dot" {
                 solver.set_dot_proof_enabled(true);
@@ -45,11 +75,438 @@ fn main() {
             else if arg == "-tex" {
                 solver.set_tex_proof_enabled(true);
                 println!("Tex proof file enabled.")
+            } //else if is "-minimize"
+            else if arg == "-minimize" {
+                solver.set_minimization_enabled(true);
+                println!("Learned clause minimization enabled.")
+            } //else if is "-no-fragments"
+            else if arg == "-no-fragments" {
+                solver.set_fragment_solvers_enabled(false);
+                println!("Linear-time solvers of 2-CNF and Horn formulas disabled.")
+            } //else if is "-no-xors"
+            else if arg == "-no-xors" {
+                solver.set_xor_detection_enabled(false);
+                println!("Detection of XOR constraints encoded as clauses disabled.")
+            } //else if is "-engine"
+            else if arg == "-engine" {
+                match args_iter.next().and_then(|name| Engine::from_name(name)) {
+                    Some(engine) => {
+                        solver.set_engine(engine);
+                        println!("Solving engine set to {}.", engine);
+                    },
+                    None => eprintln!("Error: -engine needs one of cdcl, dpll, dp, walksat, probsat, cnc."),
+                }
+            } //else if is "-optimize"
+            else if arg == "-optimize" {
+                match args_iter.next().and_then(|name| Optimization::from_name(name)) {
+                    Some(optimization) => {
+                        solver.set_optimization(optimization);
+                        println!("Optimization set to {}.", optimization);
+                    },
+                    None => eprintln!("Error: -optimize needs one of linear, binary."),
+                }
+            } //else if is "-encoding"
+            else if arg == "-encoding" {
+                match args_iter.next().and_then(|name| Encoding::from_name(name)) {
+                    Some(encoding) => {
+                        solver.set_encoding(encoding);
+                        println!("Pseudo-Boolean encoding set to {}.", encoding);
+                    },
+                    None => eprintln!("Error: -encoding needs one of pairwise, seq, totalizer, network, adder, bdd."),
+                }
+            } //else if is "-portfolio"
+            else if arg == "-portfolio" {
+                match args_iter.next().and_then(|value| value.parse::<usize>().ok()) {
+                    Some(workers) => {
+                        let workers = match workers {
+                            0 => std::thread::available_parallelism().map(|threads| threads.get()).unwrap_or(1),
+                            _ => workers,
+                        };
+                        solver.set_portfolio_workers(workers);
+                        println!("Portfolio workers set to {}.", solver.get_portfolio_workers())
+                    },
+                    None => eprintln!("Error: -portfolio needs a number of workers, 0 for one per core."),
+                }
+            } //else if is "-cube-depth"
+            else if arg == "-cube-depth" {
+                match args_iter.next().and_then(|value| value.parse::<usize>().ok()) {
+                    Some(depth) => {
+                        solver.set_cube_depth(depth);
+                        println!("Cube depth set to {}.", depth)
+                    },
+                    None => eprintln!("Error: -cube-depth needs a number of decisions."),
+                }
+            } //else if is "cube"
+            else if arg == "cube" {
+                match args_iter.next() {
+                    Some(path) => icnf_output = Some(path.clone()),
+                    None => eprintln!("Error: cube needs a file path."),
+                }
+            } //else if is "-noise"
+            else if arg == "-noise" {
+                match args_iter.next().and_then(|value| value.parse::<f64>().ok()).filter(|&noise| (0.0..=1.0).contains(&noise)) {
+                    Some(noise) => {
+                        solver.set_local_search_noise(noise);
+                        println!("WalkSAT noise set to {}.", noise)
+                    },
+                    None => eprintln!("Error: -noise needs a probability between 0 and 1."),
+                }
+            } //else if is "-cb"
+            else if arg == "-cb" {
+                match args_iter.next().and_then(|value| value.parse::<f64>().ok()).filter(|&cb| cb > 0.0) {
+                    Some(cb) => {
+                        solver.set_local_search_cb(cb);
+                        println!("ProbSAT cb set to {}.", cb)
+                    },
+                    None => eprintln!("Error: -cb needs a positive number."),
+                }
+            } //else if is "-flips"
+            else if arg == "-flips" {
+                match args_iter.next().and_then(|value| value.parse::<usize>().ok()).filter(|&flips| flips > 0) {
+                    Some(flips) => {
+                        solver.set_local_search_max_flips(flips);
+                        println!("Flip limit set to {}.", flips)
+                    },
+                    None => eprintln!("Error: -flips needs a positive number of flips."),
+                }
+            } //else if is "-restarts"
+            else if arg == "-restarts" {
+                match args_iter.next().and_then(|value| value.parse::<usize>().ok()) {
+                    Some(restarts) => {
+                        solver.set_local_search_max_restarts(restarts);
+                        println!("Restart limit set to {}.", restarts)
+                    },
+                    None => eprintln!("Error: -restarts needs a number of restarts."),
+                }
+            } //else if is "-sls-seed"
+            else if arg == "-sls-seed" {
+                match args_iter.next().and_then(|value| value.parse::<u64>().ok()) {
+                    Some(seed) => {
+                        solver.set_local_search_seed(seed);
+                        println!("Local search seed set to {}.", seed)
+                    },
+                    None => eprintln!("Error: -sls-seed needs a number."),
+                }
+            } //else if is "-heuristic"
+            else if arg == "-heuristic" {
+                match args_iter.next().map(|name| solver.set_heuristic(name)) {
+                    Some(Ok(())) => println!("Decision heuristic set to {}.", solver.get_heuristic()),
+                    _ => eprintln!("Error: -heuristic needs one of {}.", heuristics::HEURISTICS.join(", ")),
+                }
+            } //else if is "-reduce"
+            else if arg == "-reduce" {
+                match args_iter.next().map(|name| solver.set_reduction_policy(name)) {
+                    Some(Ok(())) => println!("Clause deletion policy set to {}.", solver.get_reduction_policy()),
+                    _ => eprintln!("Error: -reduce needs one of {}.", reduction::POLICIES.join(", ")),
+                }
+            } //else if is "-reduce-first"
+            else if arg == "-reduce-first" {
+                match args_iter.next().and_then(|value| value.parse::<usize>().ok()) {
+                    Some(first_limit) => solver.set_reduction_first_limit(Some(first_limit)),
+                    None => eprintln!("Error: -reduce-first needs a number of learned clauses."),
+                }
+            } //else if is "-reduce-growth"
+            else if arg == "-reduce-growth" {
+                match args_iter.next().and_then(|value| value.parse::<f64>().ok()).filter(|&growth| growth >= 1.0) {
+                    Some(growth) => solver.set_reduction_growth(growth),
+                    None => eprintln!("Error: -reduce-growth needs a factor of at least 1."),
+                }
+            } //else if is "-reduce-fraction"
+            else if arg == "-reduce-fraction" {
+                match args_iter.next().and_then(|value| value.parse::<f64>().ok()).filter(|&fraction| (0.0..=1.0).contains(&fraction)) {
+                    Some(fraction) => solver.set_reduction_fraction(fraction),
+                    None => eprintln!("Error: -reduce-fraction needs a fraction between 0 and 1."),
+                }
+            } //else if is "-preprocess"
+            else if arg == "-preprocess" {
+                solver.set_preprocessing_enabled(true);
+                println!("Preprocessing enabled.")
+            } //else if is "-bve"
+            else if arg == "-bve" {
+                solver.set_preprocessing_enabled(true);
+                solver.set_variable_elimination_enabled(true);
+                println!("Bounded variable elimination enabled.")
+            } //else if is "-probe"
+            else if arg == "-probe" {
+                solver.set_preprocessing_enabled(true);
+                solver.set_probing_enabled(true);
+                println!("Failed literal probing and equivalent literal substitution enabled.")
+            } //else if is "-bce"
+            else if arg == "-bce" {
+                solver.set_preprocessing_enabled(true);
+                solver.set_blocked_clause_elimination_enabled(true);
+                println!("Blocked clause elimination enabled.")
+            } //else if is "-cce"
+            else if arg == "-cce" {
+                solver.set_preprocessing_enabled(true);
+                solver.set_blocked_clause_elimination_enabled(true);
+                solver.set_covered_clause_elimination_enabled(true);
+                println!("Covered clause elimination enabled.")
+            } //else if is "-timeout"
+            else if arg == "-timeout" {
+                match args_iter.next().and_then(|value| value.parse::<f64>().ok()).filter(|&seconds| seconds >= 0.0) {
+                    Some(seconds) => {
+                        solver.set_timeout(Some(Duration::from_secs_f64(seconds)));
+                        println!("Timeout set to {} seconds.", seconds)
+                    },
+                    None => eprintln!("Error: -timeout needs a number of seconds."),
+                }
+            } //else if is "-conflicts"
+            else if arg == "-conflicts" {
+                match args_iter.next().and_then(|value| value.parse::<usize>().ok()) {
+                    Some(conflicts) => {
+                        solver.set_conflict_limit(Some(conflicts));
+                        println!("Conflict limit set to {}.", conflicts)
+                    },
+                    None => eprintln!("Error: -conflicts needs a number of conflicts."),
+                }
+            } //else if is "-decisions"
+            else if arg == "-decisions" {
+                match args_iter.next().and_then(|value| value.parse::<usize>().ok()) {
+                    Some(decisions) => {
+                        solver.set_decision_limit(Some(decisions));
+                        println!("Decision limit set to {}.", decisions)
+                    },
+                    None => eprintln!("Error: -decisions needs a number of decisions."),
+                }
+            } //else if is "-memory"
+            else if arg == "-memory" {
+                match args_iter.next().and_then(|value| value.parse::<usize>().ok()) {
+                    Some(megabytes) => {
+                        solver.set_memory_limit(Some(megabytes));
+                        println!("Memory limit set to {} MB.", megabytes)
+                    },
+                    None => eprintln!("Error: -memory needs a number of megabytes."),
+                }
+            } //else if is "-progress"
+            else if arg == "-progress" {
+                match args_iter.next().and_then(|value| value.parse::<usize>().ok()).filter(|&conflicts| conflicts > 0) {
+                    Some(conflicts) => {
+                        solver.set_progress_conflict_interval(Some(conflicts));
+                        println!("Progress reported every {} conflicts.", conflicts)
+                    },
+                    None => eprintln!("Error: -progress needs a number of conflicts."),
+                }
+            } //else if is "-progress-time"
+            else if arg == "-progress-time" {
+                match args_iter.next().and_then(|value| value.parse::<f64>().ok()).filter(|&seconds| seconds > 0.0) {
+                    Some(seconds) => {
+                        solver.set_progress_time_interval(Some(Duration::from_secs_f64(seconds)));
+                        println!("Progress reported every {} seconds.", seconds)
+                    },
+                    None => eprintln!("Error: -progress-time needs a number of seconds."),
+                }
+            } //else if is "--stats-json"
+            else if arg == "--stats-json" {
+                match args_iter.next() {
+                    Some(path) => {
+                        stats_json = Some(path.clone());
+                        println!("Statistics will be written to {}.", path)
+                    },
+                    None => eprintln!("Error: --stats-json needs a file path."),
+                }
+            } //else if is "--stats-csv"
+            else if arg == "--stats-csv" {
+                match args_iter.next() {
+                    Some(path) => {
+                        stats_csv = Some(path.clone());
+                        println!("Statistics will be appended to {}.", path)
+                    },
+                    None => eprintln!("Error: --stats-csv needs a file path."),
+                }
+            } //else if is "bench"
+            else if arg == "bench" {
+                match args_iter.next() {
+                    Some(directory) => {
+                        bench_directory = Some(directory.clone());
+                        println!("Benchmark mode enabled.")
+                    },
+                    None => eprintln!("Error: bench needs a directory."),
+                }
+            } //else if is "--bench-csv"
+            else if arg == "--bench-csv" {
+                match args_iter.next() {
+                    Some(path) => bench_results = path.clone(),
+                    None => eprintln!("Error: --bench-csv needs a file path."),
+                }
+            } //else if is "compare"
+            else if arg == "compare" {
+                match (args_iter.next(), args_iter.next()) {
+                    (Some(first), Some(second)) => compare_files = Some((first.clone(), second.clone())),
+                    _ => eprintln!("Error: compare needs two results files."),
+                }
+            } //else if is "--cactus"
+            else if arg == "--cactus" {
+                match args_iter.next() {
+                    Some(path) => cactus_file = path.clone(),
+                    None => eprintln!("Error: --cactus needs a file path."),
+                }
+            } //else if is "generate"
+            else if arg == "generate" {
+                match args_iter.next() {
+                    Some(kind) => generate_kind = Some(kind.clone()),
+                    None => eprintln!("Error: generate needs a kind of formula ({}).", generators::GENERATORS.join(", ")),
+                }
+            } //else if is "--out"
+            else if arg == "--out" {
+                match args_iter.next() {
+                    Some(path) => generate_output = Some(path.clone()),
+                    None => eprintln!("Error: --out needs a file path."),
+                }
+            } //else if is a parameter of the generator
+            else if generate_kind.is_some() && arg.contains('=') {
+                let (key, value) = arg.split_once('=').unwrap();
+                generate_parameters.insert(key.to_string(), value.to_string());
+            } //else if is "fuzz"
+            else if arg == "fuzz" {
+                match args_iter.next().and_then(|value| value.parse::<usize>().ok()) {
+                    Some(iterations) => fuzz_iterations = Some(iterations),
+                    None => eprintln!("Error: fuzz needs a number of formulas."),
+                }
+            } //else if is "--seed"
+            else if arg == "--seed" {
+                match args_iter.next().and_then(|value| value.parse::<u64>().ok()) {
+                    Some(seed) => fuzz_seed = seed,
+                    None => eprintln!("Error: --seed needs a number."),
+                }
+            } //else if is "--fuzz-dir"
+            else if arg == "--fuzz-dir" {
+                match args_iter.next() {
+                    Some(path) => fuzz_directory = path.clone(),
+                    None => eprintln!("Error: --fuzz-dir needs a directory."),
+                }
+            } //else if is "-backbone"
+            else if arg == "-backbone" {
+                backbone_mode = true;
+                println!("Backbone mode enabled.")
             }
         }
+        if let Some(path) = opb_file {
+            let encoding = solver.get_encoding();
+            match solver.formula.load_opb_file(&path, encoding) {
+                Ok(()) => {
+                    file_name = path;
+                    println!("File loaded successfully!");
+                    print_empty_opb(&solver);
+                },
+                Err(e) => eprintln!("Error loading file: {:?}", e),
+            }
+        }
+        if let Some((first, second)) = compare_files {
+            let timeout = match solver.get_timeout() {
+                Some(timeout) => timeout.as_secs_f64(),
+                None => limits::BENCHMARK_TIMEOUT as f64,
+            };
+            match benchmark::compare(&first, &second, timeout, &cactus_file) {
+                Ok(true) => (),
+                Ok(false) => eprintln!("Error: some answers changed between the runs or are wrong!"),
+                Err(e) => eprintln!("Error: {:?}", e),
+            }
+            return ();
+        }
+        if let Some(iterations) = fuzz_iterations {
+            interrupt::start_solving();
+            let result = fuzzer::run(iterations, fuzz_seed, &fuzz_directory);
+            interrupt::stop_solving();
+            match result {
+                Ok(0) => (),
+                Ok(failures) => eprintln!("Error: {} formulas made the solver fail, they are saved in {}", failures, fuzz_directory),
+                Err(e) => eprintln!("Error: {:?}", e),
+            }
+            return ();
+        }
+        if let Some(directory) = bench_directory {
+            if solver.get_timeout().is_none() {
+                solver.set_timeout(Some(Duration::from_secs(limits::BENCHMARK_TIMEOUT as u64)));
+            }
+            if let Err(e) = benchmark::run(&mut solver, &directory, &bench_results, &stats_csv) {
+                eprintln!("Error: {:?}", e);
+            }
+            return ();
+        }
+        if let Some(kind) = generate_kind {
+            match generators::generate(&kind, &generate_parameters) {
+                Ok(formula) => {
+                    println!("Generated a {} formula with {} variables and {} clauses.", kind, formula.get_num_variables(), formula.get_num_clauses());
+                    if let Some(path) = generate_output {
+                        match files::write_file(&path, &formula.to_dimacs()) {
+                            Ok(()) => println!("Formula written to {}", path),
+                            Err(e) => eprintln!("Error: {:?}", e),
+                        }
+                        return ();
+                    }
+                    file_name = format!("generated {}", kind);
+                    solver.formula = formula;
+                },
+                Err(()) => {
+                    print_generators_usage();
+                    return ();
+                }
+            }
+        }
+        if let (true, Some(path)) = (solver.is_formula_loaded(), &icnf_output) {
+            interrupt::start_solving();
+            let start = Instant::now();
+            let result = solver.cube();
+            interrupt::stop_solving();
+            match result {
+                Ok(Some(cubes)) => {
+                    println!("The formula is split in {} cubes.", cubes.len());
+                    match files::write_file(path, &solver.formula.to_icnf(&cubes)) {
+                        Ok(()) => println!("Cubes written to {}", path),
+                        Err(e) => eprintln!("Error: {:?}", e),
+                    }
+                    println!("Time elapsed in is: {:?}", start.elapsed());
+                },
+                Ok(None) => println!("The cubes are unknown!"),
+                Err(e) => eprintln!("Error: {:?}", e),
+            }
+            return ();
+        }
+        if solver.is_formula_loaded() && backbone_mode {
+            interrupt::start_solving();
+            let start = Instant::now();
+            let result = solver.compute_backbone();
+            interrupt::stop_solving();
+            match result {
+                Ok((sat, backbone)) => {
+                    let elapsed = start.elapsed();
+                    print_backbone(sat, &backbone);
+                    println!("Time elapsed in is: {:?}", elapsed);
+                    solver.print_stats();
+                    export_stats(&solver, &stats_json, &stats_csv, &file_name, sat, elapsed);
+                },
+                Err(e) => {
+                    eprintln!("Error: {:?}", e);
+                }
+            }
+            return ();
+        }
+        if solver.formula.get_objective().is_some() {
+            interrupt::start_solving();
+            let start = Instant::now();
+            let result = solver.optimize();
+            interrupt::stop_solving();
+            match result {
+                Ok((sat, objective)) => {
+                    let elapsed = start.elapsed();
+                    print_optimum(&solver, sat, objective);
+                    println!("Time elapsed in is: {:?}", elapsed);
+                    solver.print_stats();
+                    export_stats(&solver, &stats_json, &stats_csv, &file_name, sat, elapsed);
+                },
+                Err(e) => {
+                    eprintln!("Error: {:?}", e);
+                }
+            }
+            return ();
+        }
         if solver.is_formula_loaded() {
+            interrupt::start_solving();
             let start = Instant::now();
-            match solver.solve() {
+            let result = solver.solve();
+            interrupt::stop_solving();
+            match result {
                 Ok(sat) => {
                     match sat {
                         SAT::Satisfiable => {
@@ -64,8 +521,10 @@ fn main() {
                             println!("The formula is unknown!");
                         },
                     }
-                    println!("Time elapsed in is: {:?}", start.elapsed());
+                    let elapsed = start.elapsed();
+                    println!("Time elapsed in is: {:?}", elapsed);
                     solver.print_stats();
+                    export_stats(&solver, &stats_json, &stats_csv, &file_name, sat, elapsed);
                 },
                 Err(e) => {
                     eprintln!("Error: {:?}", e);
@@ -74,19 +533,29 @@ fn main() {
             return ();
         }
     }
+
+    solver.set_progress_live(true);
     
     loop {
 
         let mut choices: Vec<&str> = Vec::new();
 
-        if solver.is_formula_loaded() {
-            choices.push("Solve");
+        if solver.is_formula_loaded() || solver.formula.get_objective().is_some() {
+            // The constraints of an OPB file can be encoded in no clause, only its objective is left to optimize.
+            if solver.is_formula_loaded() {
+                choices.push("Solve");
+                choices.push("Backbone");
+            }
+            if solver.formula.get_objective().is_some() {
+                choices.push("Optimize");
+            }
             choices.push("Solver options");
             choices.push("Print");
             choices.push("Clear formula");
         } else {
-            choices.push("Load CNF file");
+            choices.push("Load CNF or OPB file");
             choices.push("Write the formula");
+            choices.push("Generate a formula");
         }
 
         choices.push("Exit");
@@ -101,8 +570,18 @@ fn main() {
             ""
         ], choices) {
             Ok(choice) => {
-                if choice == "Load CNF file" {
-                    match input::input("Insert the path of the CNF file: ") {
+                if choice == "Load CNF or OPB file" {
+                    match input::input("Insert the path of the CNF or OPB file: ") {
+                        Ok(path) if path.ends_with(".opb") => {
+                            let encoding = solver.get_encoding();
+                            match solver.formula.load_opb_file(&path, encoding) {
+                                Ok(()) => {
+                                    println!("File loaded successfully!");
+                                    print_empty_opb(&solver);
+                                },
+                                Err(e) => eprintln!("Error loading file: {:?}", e),
+                            }
+                        },
                         Ok(path) => match solver.formula.load_file(&path) {
                             Ok(()) => println!("File loaded successfully!"),
                             Err(e) => eprintln!("Error loading file: {:?}", e),
@@ -165,12 +644,41 @@ fn main() {
                             },
                             Err(_e) => (),
                         }
+                } else if choice == "Generate a formula" {
+                    let mut kinds: Vec<&str> = generators::GENERATORS.to_vec();
+                    kinds.push("Back");
+                    match input::choice_menu(vec!["Select the kind of formula:"], kinds) {
+                        Ok(kind) if kind != "Back" => {
+                            print_generators_usage();
+                            match input::input("Insert the parameters as key=value separated by spaces: ") {
+                                Ok(line) => {
+                                    let parameters: HashMap<String, String> = line.split_whitespace()
+                                        .filter_map(|parameter| parameter.split_once('='))
+                                        .map(|(key, value)| (key.to_string(), value.to_string()))
+                                        .collect();
+                                    match generators::generate(&kind, &parameters) {
+                                        Ok(formula) => {
+                                            solver.formula = formula;
+                                            println!("Generated a {} formula with {} variables and {} clauses.", kind, solver.formula.get_num_variables(), solver.formula.get_num_clauses());
+                                        },
+                                        Err(()) => eprintln!("Error: missing or invalid parameters."),
+                                    }
+                                },
+                                Err(e) => eprintln!("Error: {:?}", e),
+                            }
+                            input::pause(Option::None);
+                        },
+                        _ => (),
+                    }
                 } else if choice == "Clear formula" {
                     solver.reset();
                 } else if choice == "Solve" {
                     solver.reset_solve();
+                    interrupt::start_solving();
                     let start = Instant::now();
-                    match solver.solve() {
+                    let result = solver.solve();
+                    interrupt::stop_solving();
+                    match result {
                         Ok(sat) => {
                             match sat {
                                 SAT::Satisfiable => {
@@ -193,7 +701,61 @@ fn main() {
                             eprintln!("Error: {:?}", e);
                         }
                     }
+                } else if choice == "Backbone" {
+                    solver.reset_solve();
+                    interrupt::start_solving();
+                    let start = Instant::now();
+                    let result = solver.compute_backbone();
+                    interrupt::stop_solving();
+                    match result {
+                        Ok((sat, backbone)) => {
+                            print_backbone(sat, &backbone);
+                            println!("Time elapsed in is: {:?}", start.elapsed());
+                            solver.print_stats();
+                            input::pause(Option::None);
+                        },
+                        Err(e) => {
+                            eprintln!("Error: {:?}", e);
+                        }
+                    }
+                } else if choice == "Optimize" {
+                    solver.reset_solve();
+                    interrupt::start_solving();
+                    let start = Instant::now();
+                    let result = solver.optimize();
+                    interrupt::stop_solving();
+                    match result {
+                        Ok((sat, objective)) => {
+                            print_optimum(&solver, sat, objective);
+                            println!("Time elapsed in is: {:?}", start.elapsed());
+                            solver.print_stats();
+                            input::pause(Option::None);
+                        },
+                        Err(e) => {
+                            eprintln!("Error: {:?}", e);
+                        }
+                    }
                 } else if choice == "Solver options" {
+                    let engine_names: Vec<String> = Engine::ALL.iter().map(|engine| engine.to_string()).collect();
+                    let engine_selected = Engine::ALL.iter().position(|&engine| engine == solver.get_engine()).unwrap_or(0);
+                    let worker_names: Vec<String> = portfolio::WORKER_CHOICES.iter().map(|workers| workers.to_string()).collect();
+                    let worker_selected = portfolio::WORKER_CHOICES.iter().position(|&workers| workers == solver.get_portfolio_workers()).unwrap_or(0);
+                    let depth_names: Vec<String> = cube::DEPTH_CHOICES.iter().map(|depth| depth.to_string()).collect();
+                    let depth_selected = cube::DEPTH_CHOICES.iter().position(|&depth| depth == solver.get_cube_depth()).unwrap_or(0);
+                    let optimization_names: Vec<String> = Optimization::ALL.iter().map(|optimization| optimization.to_string()).collect();
+                    let optimization_selected = Optimization::ALL.iter().position(|&optimization| optimization == solver.get_optimization()).unwrap_or(0);
+                    let encoding_names: Vec<String> = Encoding::ALL.iter().map(|encoding| encoding.to_string()).collect();
+                    let encoding_selected = Encoding::ALL.iter().position(|&encoding| encoding == solver.get_encoding()).unwrap_or(0);
+                    let heuristic_names: Vec<String> = heuristics::HEURISTICS.iter().map(|name| name.to_string()).collect();
+                    let heuristic_selected = heuristics::HEURISTICS.iter().position(|&name| name == solver.get_heuristic()).unwrap_or(0);
+                    let policy_names: Vec<String> = reduction::POLICIES.iter().map(|name| name.to_string()).collect();
+                    let policy_selected = reduction::POLICIES.iter().position(|&name| name == solver.get_reduction_policy()).unwrap_or(0);
+                    let (timeout_editor, timeout_values) = limit_editor(&limits::TIMEOUT_CHOICES, solver.get_timeout().map(|timeout| timeout.as_secs() as usize), "s");
+                    let (conflicts_editor, conflicts_values) = limit_editor(&limits::CONFLICT_LIMIT_CHOICES, solver.get_conflict_limit(), "");
+                    let (decisions_editor, decisions_values) = limit_editor(&limits::DECISION_LIMIT_CHOICES, solver.get_decision_limit(), "");
+                    let (memory_editor, memory_values) = limit_editor(&limits::MEMORY_LIMIT_CHOICES, solver.get_memory_limit(), "MB");
+                    let (progress_conflicts_editor, progress_conflicts_values) = limit_editor(&progress::CONFLICT_INTERVAL_CHOICES, solver.get_progress_conflict_interval(), "conflicts");
+                    let (progress_time_editor, progress_time_values) = limit_editor(&progress::TIME_INTERVAL_CHOICES, solver.get_progress_time_interval().map(|interval| interval.as_secs() as usize), "s");
                     match input::editor_menu(
                         vec![
                             "Change the solver options:"
@@ -201,7 +763,28 @@ fn main() {
                         vec![
                             ("Print .dot proof file", EditorTypes::Bool(solver.is_dot_proof_enabled())),
                             ("Print .txt proof file", EditorTypes::Bool(solver.is_txt_proof_enabled())),
-                            ("Print .tex proof file", EditorTypes::Bool(solver.is_tex_proof_enabled()))
+                            ("Print .tex proof file", EditorTypes::Bool(solver.is_tex_proof_enabled())),
+                            ("Clause minimization", EditorTypes::Bool(solver.is_minimization_enabled())),
+                            ("Solving engine", EditorTypes::StringArray(engine_names, engine_selected)),
+                            ("Fragment solvers", EditorTypes::Bool(solver.is_fragment_solvers_enabled())),
+                            ("XOR detection", EditorTypes::Bool(solver.is_xor_detection_enabled())),
+                            ("Portfolio workers", EditorTypes::StringArray(worker_names, worker_selected)),
+                            ("Cube depth", EditorTypes::StringArray(depth_names, depth_selected)),
+                            ("Optimization", EditorTypes::StringArray(optimization_names, optimization_selected)),
+                            ("Pseudo-Boolean encoding", EditorTypes::StringArray(encoding_names, encoding_selected)),
+                            ("Decision heuristic", EditorTypes::StringArray(heuristic_names, heuristic_selected)),
+                            ("Clause deletion", EditorTypes::StringArray(policy_names, policy_selected)),
+                            ("Preprocessing", EditorTypes::Bool(solver.is_preprocessing_enabled())),
+                            ("Variable elimination", EditorTypes::Bool(solver.is_variable_elimination_enabled())),
+                            ("Probing", EditorTypes::Bool(solver.is_probing_enabled())),
+                            ("Blocked clause elimination", EditorTypes::Bool(solver.is_blocked_clause_elimination_enabled())),
+                            ("Covered clause elimination", EditorTypes::Bool(solver.is_covered_clause_elimination_enabled())),
+                            ("Timeout", timeout_editor),
+                            ("Conflict limit", conflicts_editor),
+                            ("Decision limit", decisions_editor),
+                            ("Memory limit", memory_editor),
+                            ("Progress every", progress_conflicts_editor),
+                            ("Progress interval", progress_time_editor)
                         ]
                     ) {
                         Ok(results) => {
@@ -216,6 +799,71 @@ fn main() {
                                     ("Print .tex proof file", enabled) => {
                                         solver.set_tex_proof_enabled(enabled == 1);
                                     },
+                                    ("Clause minimization", enabled) => {
+                                        solver.set_minimization_enabled(enabled == 1);
+                                    },
+                                    ("Solving engine", selected) => {
+                                        solver.set_engine(Engine::ALL[selected]);
+                                    },
+                                    ("Fragment solvers", enabled) => {
+                                        solver.set_fragment_solvers_enabled(enabled == 1);
+                                    },
+                                    ("XOR detection", enabled) => {
+                                        solver.set_xor_detection_enabled(enabled == 1);
+                                    },
+                                    ("Portfolio workers", selected) => {
+                                        solver.set_portfolio_workers(portfolio::WORKER_CHOICES[selected]);
+                                    },
+                                    ("Cube depth", selected) => {
+                                        solver.set_cube_depth(cube::DEPTH_CHOICES[selected]);
+                                    },
+                                    ("Optimization", selected) => {
+                                        solver.set_optimization(Optimization::ALL[selected]);
+                                    },
+                                    ("Pseudo-Boolean encoding", selected) => {
+                                        solver.set_encoding(Encoding::ALL[selected]);
+                                    },
+                                    ("Decision heuristic", selected) => {
+                                        if heuristics::HEURISTICS[selected] != solver.get_heuristic() {
+                                            let _ = solver.set_heuristic(heuristics::HEURISTICS[selected]);
+                                        }
+                                    },
+                                    ("Clause deletion", selected) => {
+                                        let _ = solver.set_reduction_policy(reduction::POLICIES[selected]);
+                                    },
+                                    ("Preprocessing", enabled) => {
+                                        solver.set_preprocessing_enabled(enabled == 1);
+                                    },
+                                    ("Variable elimination", enabled) => {
+                                        solver.set_variable_elimination_enabled(enabled == 1);
+                                    },
+                                    ("Probing", enabled) => {
+                                        solver.set_probing_enabled(enabled == 1);
+                                    },
+                                    ("Blocked clause elimination", enabled) => {
+                                        solver.set_blocked_clause_elimination_enabled(enabled == 1);
+                                    },
+                                    ("Covered clause elimination", enabled) => {
+                                        solver.set_covered_clause_elimination_enabled(enabled == 1);
+                                    },
+                                    ("Timeout", selected) => {
+                                        solver.set_timeout(timeout_values[selected].map(|seconds| Duration::from_secs(seconds as u64)));
+                                    },
+                                    ("Conflict limit", selected) => {
+                                        solver.set_conflict_limit(conflicts_values[selected]);
+                                    },
+                                    ("Decision limit", selected) => {
+                                        solver.set_decision_limit(decisions_values[selected]);
+                                    },
+                                    ("Memory limit", selected) => {
+                                        solver.set_memory_limit(memory_values[selected]);
+                                    },
+                                    ("Progress every", selected) => {
+                                        solver.set_progress_conflict_interval(progress_conflicts_values[selected]);
+                                    },
+                                    ("Progress interval", selected) => {
+                                        solver.set_progress_time_interval(progress_time_values[selected].map(|seconds| Duration::from_secs(seconds as u64)));
+                                    },
                                     _ => (),
                                 };
                             }
@@ -229,6 +877,8 @@ fn main() {
                         vec![
                             format!("Number of variables: {}", &solver.formula.get_num_variables()).as_str(),
                             format!("Number of clauses: {}", &solver.formula.get_num_clauses()).as_str(),
+                            format!("Number of XOR constraints: {}", &solver.formula.get_xors().len()).as_str(),
+                            format!("Class: {}", &solver.formula.get_fragment()).as_str(),
                             "",
                             "Select the print mode:"
                         ],
@@ -262,4 +912,138 @@ fn main() {
 
     println!("Bye!");
     
+}
+
+/// Prints the backbone of the formula
+/// 
+/// # Arguments
+/// 
+/// * `sat` - The result of the formula
+/// * `backbone` - The backbone literals
+/// 
+fn print_backbone(sat: SAT, backbone: &Vec<isize>) {
+    match sat {
+        SAT::Satisfiable => {
+            if backbone.is_empty() {
+                println!("The formula is satisfiable, its backbone is empty!");
+            } else {
+                println!("The formula is satisfiable, the following literals are true in every model:");
+                println!("{}", backbone.iter().map(|x| x.to_string()).collect::<Vec<String>>().join(" "));
+            }
+        },
+        SAT::Unsatisfiable => {
+            println!("The formula is unsatisfiable, it has no backbone!");
+        },
+        SAT::Unknown => {
+            println!("The formula is unknown!");
+        },
+    }
+}
+
+/// Prints that a loaded OPB file is satisfiable if its constraints are encoded in no clause and it has no objective function,
+/// the solver cannot solve a formula without clauses
+/// 
+/// # Arguments
+/// 
+/// * `solver` - The solver with the loaded formula
+/// 
+fn print_empty_opb(solver: &Solver) {
+    if !solver.is_formula_loaded() && solver.formula.get_objective().is_none() {
+        println!("The constraints are encoded in no clause, so every assignment satisfies them.");
+        println!("The formula is satisfiable!");
+    }
+}
+
+/// Prints the result of the optimization and the best model found
+/// 
+/// # Arguments
+/// 
+/// * `solver` - The solver, its model is the best one
+/// * `sat` - The result of the formula
+/// * `objective` - The best value of the objective function
+/// 
+fn print_optimum(solver: &Solver, sat: SAT, objective: Option<isize>) {
+    match (sat, objective) {
+        (SAT::Satisfiable, Some(objective)) => {
+            println!("The optimum of the objective function is {}!", objective);
+            println!("The following model is optimal:");
+            solver.print_model();
+        },
+        (SAT::Unknown, Some(objective)) => {
+            println!("The optimum is unknown, the best value found is {}!", objective);
+            println!("The following model is the best one found:");
+            solver.print_model();
+        },
+        (SAT::Unsatisfiable, _) => {
+            println!("The formula is unsatisfiable!");
+        },
+        _ => {
+            println!("The formula is unknown!");
+        },
+    }
+}
+
+/// Builds the editor entry of an optional numeric setting, like a solver limit.
+/// The current value is added to the choices if it is not one of them.
+/// 
+/// # Arguments
+/// 
+/// * `choices` - The values that can be selected.
+/// * `current` - The current value of the setting, None if it is disabled.
+/// * `unit` - The unit of measure of the setting.
+/// 
+/// # Returns
+/// 
+/// * `EditorTypes` - The editor entry, with "None" as first choice.
+/// * `Vec<Option<usize>>` - The value of each choice.
+/// 
+fn limit_editor(choices: &[usize], current: Option<usize>, unit: &str) -> (EditorTypes, Vec<Option<usize>>) {
+    let mut values: Vec<Option<usize>> = vec![None];
+    values.extend(choices.iter().map(|&value| Some(value)));
+    if current.is_some() && !values.contains(&current) {
+        values.push(current);
+    }
+
+    let labels: Vec<String> = values.iter().map(|value| match value {
+        Some(value) => format!("{} {}", value, unit).trim().to_string(),
+        None => "None".to_string(),
+    }).collect();
+    let selected = values.iter().position(|&value| value == current).unwrap_or(0);
+
+    return (EditorTypes::StringArray(labels, selected), values);
+}
+
+/// Prints the kinds of formulas of the generators and their parameters
+fn print_generators_usage() {
+    println!("Usage: generate <kind> [key=value ...] [--out <file>]");
+    println!("  ksat      n=<variables> m=<clauses> | ratio=<clauses per variable> (4.26) k=<literals per clause> (3) seed=<seed> (0)");
+    println!("  php       n=<holes>");
+    println!("  parity    n=<variables> m=<constraints> (n) k=<variables per constraint> (3, at most 10) seed=<seed> (0)");
+    println!("  coloring  n=<vertices> m=<edges> (2n) k=<colors> (3) seed=<seed> (0)");
+}
+
+/// Writes the statistics of the solver to the requested files.
+/// The JSON file is replaced, while a row is appended to the CSV file, with the header if the file is new.
+/// 
+/// # Arguments
+/// 
+/// * `solver` - The solver that solved the instance.
+/// * `json_path` - The path of the JSON file, None to skip it.
+/// * `csv_path` - The path of the CSV file, None to skip it.
+/// * `file_name` - The name of the solved instance.
+/// * `sat` - The result of the solver.
+/// * `time` - The time elapsed solving the instance.
+/// 
+fn export_stats(solver: &Solver, json_path: &Option<String>, csv_path: &Option<String>, file_name: &str, sat: SAT, time: Duration) {
+    if let Some(path) = json_path {
+        if let Err(e) = files::write_file(path, &solver.get_stats_json(file_name, sat, time)) {
+            eprintln!("Error writing the statistics: {:?}", e);
+        }
+    }
+    if let Some(path) = csv_path {
+        let header = !files::file_exists(path);
+        if let Err(e) = files::append_file(path, &solver.get_stats_csv(file_name, sat, time, header)) {
+            eprintln!("Error writing the statistics: {:?}", e);
+        }
+    }
 }
\ No newline at end of file
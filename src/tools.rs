@@ -1 +1,4 @@
-pub mod clause_tools;
\ No newline at end of file
+pub mod clause_tools;
+pub mod benchmark;
+pub mod generators;
+pub mod fuzzer;
\ No newline at end of file
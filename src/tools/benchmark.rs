@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crate::{files, interrupt};
+use crate::consts::sat::SAT;
+use crate::classes::{solver::Solver, stats::Stats};
+
+/// Runs the solver over every CNF file of a directory
+/// Every instance is solved from scratch with the options of the solver, the results are checked against the expected status
+/// and the models are verified against the formula. A row for each instance is written to the results file, then a summary is printed.
+/// 
+/// # Arguments
+/// 
+/// * `solver` - The solver, with the options and the limits to use for every instance
+/// * `directory` - The path of the directory
+/// * `results_path` - The path of the CSV file of the results, it is replaced
+/// * `stats_path` - The path of a CSV file where to append the full statistics of every instance, None to skip it
+/// 
+/// # Returns
+/// 
+/// * `Result<(), std::io::Error>` - The result of the operation
+/// 
+pub fn run(solver: &mut Solver, directory: &str, results_path: &str, stats_path: &Option<String>) -> Result<(), std::io::Error> {
+
+    let paths = files::list_files(directory, "cnf")?;
+    let mut results = String::from("file,expected,result,status,time,conflicts,decisions,peak_memory\n");
+
+    let mut solved_sat = 0;
+    let mut solved_unsat = 0;
+    let mut unknown = 0;
+    let mut wrong = 0;
+    let mut invalid_models = 0;
+    let mut total_time = 0.0;
+
+    println!("{:<30} {:>8} {:>8} {:>10} {:>14}", "Instance", "Expected", "Result", "Time", "Status");
+
+    for path in paths.iter() {
+        let contents = files::read_file(path)?;
+        let expected = expected_status(path, &contents);
+
+        solver.reset();
+        solver.formula.load_dimacs(contents);
+
+        interrupt::start_solving();
+        let start = Instant::now();
+        let result = solver.solve();
+        interrupt::stop_solving();
+        let elapsed = start.elapsed();
+
+        let sat = match result {
+            Ok(sat) => sat,
+            Err(()) => {
+                eprintln!("Error solving {}", path);
+                continue;
+            }
+        };
+
+        let status = match (sat, expected) {
+            (SAT::Unknown, _) => "UNKNOWN",
+            (SAT::Satisfiable, _) if !solver.verify_model() => "INVALID MODEL",
+            (_, None) => "UNCHECKED",
+            (sat, Some(expected)) if sat == expected => "OK",
+            _ => "WRONG",
+        };
+        match status {
+            "UNKNOWN" => unknown += 1,
+            "INVALID MODEL" => invalid_models += 1,
+            "WRONG" => wrong += 1,
+            _ => match sat {
+                SAT::Satisfiable => solved_sat += 1,
+                _ => solved_unsat += 1,
+            },
+        }
+        total_time += elapsed.as_secs_f64();
+
+        let expected_formatted = match expected {
+            Some(expected) => expected.to_string(),
+            None => "?".to_string(),
+        };
+        println!("{:<30} {:>8} {:>8} {:>9.3}s {:>14}", path, expected_formatted, sat.to_string(), elapsed.as_secs_f64(), status);
+
+        let stats = solver.get_stats();
+        results.push_str(&format!(
+            "{},{},{},{},{:.6},{},{},{}\n",
+            Stats::csv_string(path),
+            expected_formatted,
+            sat,
+            status,
+            elapsed.as_secs_f64(),
+            stats.get_conflicts(),
+            stats.get_decisions(),
+            stats.get_physical_memory_bytes()
+        ));
+
+        if let Some(stats_path) = stats_path {
+            let header = !files::file_exists(stats_path);
+            files::append_file(stats_path, &solver.get_stats_csv(path, sat, elapsed, header))?;
+        }
+
+        if interrupt::is_interrupted() {
+            println!("Benchmark interrupted by the user.");
+            break;
+        }
+    }
+
+    files::write_file(results_path, &results)?;
+
+    println!("");
+    println!("Instances: {}", paths.len());
+    println!("Solved: {} ({} SAT, {} UNSAT)", solved_sat + solved_unsat, solved_sat, solved_unsat);
+    println!("Unknown: {}", unknown);
+    println!("Wrong results: {}", wrong);
+    println!("Invalid models: {}", invalid_models);
+    println!("Total time: {:.3}s", total_time);
+    println!("Results written to {}", results_path);
+
+    return Ok(());
+}
+
+/// Infers the expected status of an instance
+/// A `c status` comment in the file has precedence over the name, where `uuf` instances are unsatisfiable and `uf` instances satisfiable.
+/// 
+/// # Arguments
+/// 
+/// * `path` - The path of the instance
+/// * `contents` - The content of the instance
+/// 
+/// # Returns
+/// 
+/// * `Option<SAT>` - The expected status, None if it is not known
+/// 
+pub fn expected_status(path: &str, contents: &str) -> Option<SAT> {
+    for line in contents.lines() {
+        let words: Vec<String> = line.split_whitespace().map(|word| word.to_lowercase()).collect();
+        if words.len() >= 3 && words[0] == "c" && words[1] == "status" {
+            match words[2].as_str() {
+                "sat" | "satisfiable" => return Some(SAT::Satisfiable),
+                "unsat" | "unsatisfiable" => return Some(SAT::Unsatisfiable),
+                _ => (),
+            }
+        }
+    }
+
+    let name = path.rsplit(['/', '\\']).next().unwrap_or(path).to_lowercase();
+    if name.starts_with("uuf") {
+        return Some(SAT::Unsatisfiable);
+    }
+    if name.starts_with("uf") {
+        return Some(SAT::Satisfiable);
+    }
+    return None;
+}
+
+/// Compares two benchmark runs
+/// The instances are matched by file name. For each instance the speedup of the second run is printed, and an answer
+/// that changes between the runs is reported as a correctness failure, whatever the status of the answers. A wrong answer or an
+/// invalid model in either run is a correctness failure too. Unsolved instances count twice the timeout in the PAR-2 score.
+/// The cactus data file has, for each number of solved instances, the timeout each run needs to solve that many instances.
+/// 
+/// # Arguments
+/// 
+/// * `first_path` - The path of the results file of the first run
+/// * `second_path` - The path of the results file of the second run
+/// * `timeout` - The timeout of the runs in seconds
+/// * `cactus_path` - The path of the cactus data file, it is replaced
+/// 
+/// # Returns
+/// 
+/// * `Result<bool, std::io::Error>` - true if no answer changed between the runs and no answer is wrong, false otherwise
+/// 
+pub fn compare(first_path: &str, second_path: &str, timeout: f64, cactus_path: &str) -> Result<bool, std::io::Error> {
+
+    let first_run = read_results(first_path)?;
+    let second_run = read_results(second_path)?;
+
+    let mut instances: Vec<&String> = first_run.keys().filter(|name| second_run.contains_key(*name)).collect();
+    instances.sort();
+
+    let mut changed_answers = 0;
+    let mut wrong_answers = 0;
+    let mut first_par2 = 0.0;
+    let mut second_par2 = 0.0;
+    let mut first_times: Vec<f64> = Vec::new();
+    let mut second_times: Vec<f64> = Vec::new();
+
+    println!("{:<30} {:>10} {:>10} {:>10} {}", "Instance", "First", "Second", "Speedup", "");
+
+    for name in instances.iter() {
+        let (first_result, first_status, first_time) = &first_run[*name];
+        let (second_result, second_status, second_time) = &second_run[*name];
+        let first_solved = is_solved(first_status);
+        let second_solved = is_solved(second_status);
+        let definitive = |result: &String| result == &SAT::Satisfiable.to_string() || result == &SAT::Unsatisfiable.to_string();
+        let wrong = |status: &String| status == "WRONG" || status == "INVALID MODEL";
+
+        let note = if definitive(first_result) && definitive(second_result) && first_result != second_result {
+            changed_answers += 1;
+            format!("ANSWER CHANGED ({} -> {})", first_result, second_result)
+        } else if wrong(first_status) || wrong(second_status) {
+            wrong_answers += 1;
+            format!("{} -> {}", first_status, second_status)
+        } else {
+            match (first_solved, second_solved) {
+                (true, false) => "solved only by the first run".to_string(),
+                (false, true) => "solved only by the second run".to_string(),
+                _ => String::new(),
+            }
+        };
+
+        let speedup = match (first_solved, second_solved) {
+            (true, true) => format!("{:.2}x", first_time / second_time.max(f64::EPSILON)),
+            _ => "-".to_string(),
+        };
+
+        let format_time = |time: f64, solved: bool| match solved {
+            true => format!("{:.3}s", time),
+            false => "-".to_string(),
+        };
+        println!("{:<30} {:>10} {:>10} {:>10} {}", name, format_time(*first_time, first_solved), format_time(*second_time, second_solved), speedup, note);
+
+        first_par2 += if first_solved { *first_time } else { 2.0 * timeout };
+        second_par2 += if second_solved { *second_time } else { 2.0 * timeout };
+        if first_solved {
+            first_times.push(*first_time);
+        }
+        if second_solved {
+            second_times.push(*second_time);
+        }
+    }
+
+    first_times.sort_by(|a, b| a.total_cmp(b));
+    second_times.sort_by(|a, b| a.total_cmp(b));
+    let mut cactus = String::from("solved first second\n");
+    for idx in 0..first_times.len().max(second_times.len()) {
+        let format_time = |time: Option<&f64>| match time {
+            Some(time) => format!("{:.6}", time),
+            None => "nan".to_string(),
+        };
+        cactus.push_str(&format!("{} {} {}\n", idx + 1, format_time(first_times.get(idx)), format_time(second_times.get(idx))));
+    }
+    files::write_file(cactus_path, &cactus)?;
+
+    println!("");
+    println!("Common instances: {}", instances.len());
+    println!("Solved: {} first, {} second", first_times.len(), second_times.len());
+    println!("PAR-2: {:.3} first, {:.3} second", first_par2, second_par2);
+    println!("Changed answers: {}", changed_answers);
+    println!("Wrong answers or invalid models: {}", wrong_answers);
+    println!("Cactus data written to {}", cactus_path);
+
+    return Ok(changed_answers == 0 && wrong_answers == 0);
+}
+
+/// Reads a results file written by the benchmark mode
+/// 
+/// # Arguments
+/// 
+/// * `path` - The path of the results file
+/// 
+/// # Returns
+/// 
+/// * `Result<HashMap<String, (String, String, f64)>, std::io::Error>` - For each instance file name, its result, its status and its time
+/// 
+fn read_results(path: &str) -> Result<HashMap<String, (String, String, f64)>, std::io::Error> {
+    let contents = files::read_file(path)?;
+    let mut lines = contents.lines();
+
+    let header = parse_csv_line(lines.next().unwrap_or(""));
+    let column = |name: &str| header.iter().position(|field| field == name);
+    let (file_idx, result_idx, status_idx, time_idx) = match (column("file"), column("result"), column("status"), column("time")) {
+        (Some(file_idx), Some(result_idx), Some(status_idx), Some(time_idx)) => (file_idx, result_idx, status_idx, time_idx),
+        _ => return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, format!("{} is not a benchmark results file", path))),
+    };
+
+    let mut results = HashMap::new();
+    for line in lines {
+        let fields = parse_csv_line(line);
+        if fields.len() <= file_idx.max(result_idx).max(status_idx).max(time_idx) {
+            continue;
+        }
+        let name = fields[file_idx].rsplit(['/', '\\']).next().unwrap_or("").to_string();
+        let time = fields[time_idx].parse::<f64>().unwrap_or(f64::INFINITY);
+        results.insert(name, (fields[result_idx].clone(), fields[status_idx].clone(), time));
+    }
+    return Ok(results);
+}
+
+/// Check if the status of an instance in a results file is a solved instance
+/// 
+/// # Arguments
+/// 
+/// * `status` - The status of the instance
+/// 
+/// # Returns
+/// 
+/// * `bool` - true if the answer is correct or could not be checked, false if it is unknown, wrong or an invalid model
+/// 
+fn is_solved(status: &str) -> bool {
+    return status == "OK" || status == "UNCHECKED";
+}
+
+/// Splits a CSV line into its fields
+/// 
+/// # Arguments
+/// 
+/// * `line` - The line
+/// 
+/// # Returns
+/// 
+/// * `Vec<String>` - The fields, without the quotes
+/// 
+fn parse_csv_line(line: &str) -> Vec<String> {
+    let mut fields: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(character) = chars.next() {
+        match (character, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            },
+            ('"', _) => quoted = !quoted,
+            (',', false) => fields.push(std::mem::take(&mut field)),
+            _ => field.push(character),
+        }
+    }
+    fields.push(field);
+    return fields;
+}
\ No newline at end of file
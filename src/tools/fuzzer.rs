@@ -0,0 +1,763 @@
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use rand::{rngs::StdRng, seq::index::sample, Rng, SeedableRng};
+
+use crate::{files, interrupt};
+use crate::consts::{encoding::{Comparator, Encoding}, engine::Engine, heuristics, reduction, sat::SAT};
+use crate::classes::{formula::Formula, solver::Solver, xor::Xor};
+
+/// The maximum number of variables of a random formula, the reference solver enumerates every assignment
+const MAX_VARIABLES: usize = 12;
+
+/// The maximum number of flips of a try of the local search engines, the small formulas are solved in a few flips
+const MAX_FLIPS: usize = 1000;
+
+/// The number of workers of the portfolio configuration
+const PORTFOLIO_WORKERS: usize = 4;
+
+/// The maximum number of variables of a random pseudo-Boolean constraint, every assignment of them is checked
+const MAX_CONSTRAINT_VARIABLES: usize = 5;
+
+/// The maximum absolute weight of a term of a random pseudo-Boolean constraint
+const MAX_WEIGHT: isize = 4;
+
+/// A small random formula
+#[derive(Clone)]
+struct Instance {
+    clauses: Vec<Vec<isize>>,
+    /// The literals of the native XOR constraints, an odd number of them must be true
+    xors: Vec<Vec<isize>>,
+}
+
+impl Instance {
+    /// Returns the number of constraints of the formula
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The number of clauses and XOR constraints
+    /// 
+    fn len(&self) -> usize {
+        return self.clauses.len() + self.xors.len();
+    }
+
+    /// Returns the greatest variable of the formula
+    /// 
+    /// # Returns
+    /// 
+    /// * `usize` - The greatest variable of the clauses and the XOR constraints, 0 if there is none
+    /// 
+    fn num_variables(&self) -> usize {
+        return self.clauses.iter().chain(self.xors.iter()).flatten().map(|literal| literal.abs() as usize).max().unwrap_or(0);
+    }
+
+    /// Check if an assignment satisfies every constraint of the formula
+    /// 
+    /// # Arguments
+    /// 
+    /// * `assignment` - The values of the variables, the bit i is the value of the variable i + 1
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the assignment satisfies every clause and every XOR constraint
+    /// 
+    fn is_satisfied_by(&self, assignment: usize) -> bool {
+        let is_true = |literal: isize| (assignment >> (literal.abs() - 1) & 1 == 1) == (literal > 0);
+        return self.clauses.iter().all(|clause| clause.iter().any(|&literal| is_true(literal)))
+            && self.xors.iter().all(|xor| xor.iter().filter(|&&literal| is_true(literal)).count() % 2 == 1);
+    }
+}
+
+/// A random linear pseudo-Boolean constraint Σ w·l (comparator) bound, every variable from 1 to n has a term
+struct PseudoBoolean {
+    terms: Vec<(isize, isize)>,
+    comparator: Comparator,
+    bound: isize,
+}
+
+impl PseudoBoolean {
+    /// Check if an assignment of the variables satisfies the constraint
+    /// 
+    /// # Arguments
+    /// 
+    /// * `assignment` - The values of the variables, the bit i is the value of the variable i + 1
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the sum of the weights of the true literals is compared with the bound as required
+    /// 
+    fn is_satisfied_by(&self, assignment: usize) -> bool {
+        let sum: isize = self.terms.iter()
+            .filter(|&&(_, literal)| (assignment >> (literal.abs() - 1) & 1 == 1) == (literal > 0))
+            .map(|&(weight, _)| weight)
+            .sum();
+        return match self.comparator {
+            Comparator::AtMost => sum <= self.bound,
+            Comparator::AtLeast => sum >= self.bound,
+            Comparator::Exactly => sum == self.bound,
+        };
+    }
+}
+
+impl fmt::Display for PseudoBoolean {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let terms: Vec<String> = self.terms.iter()
+            .map(|&(weight, literal)| format!("{:+} {}x{}", weight, if literal < 0 { "~" } else { "" }, literal.abs()))
+            .collect();
+        write!(f, "{} {} {} ;", terms.join(" "), self.comparator, self.bound)
+    }
+}
+
+/// A configuration of the options of the solver
+#[derive(Clone, Copy)]
+struct Configuration {
+    engine: Engine,
+    workers: usize,
+    heuristic: &'static str,
+    reduction: &'static str,
+    preprocessing: bool,
+    variable_elimination: bool,
+    probing: bool,
+    blocked_clause_elimination: bool,
+    covered_clause_elimination: bool,
+    minimization: bool,
+}
+
+impl Configuration {
+    /// Returns every configuration of the options
+    /// Every decision heuristic is combined with the other options, the techniques of the preprocessor are combined only when the preprocessing is enabled.
+    /// The other deletion policies are combined with the heuristics only, and they reduce the learned clauses after every conflict.
+    /// A portfolio runs the default heuristic with clause sharing between its workers.
+    /// DPLL is combined with every heuristic, Davis-Putnam, cube-and-conquer and the local search engines use the default heuristic.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Vec<Configuration>` - The configurations
+    /// 
+    fn all() -> Vec<Configuration> {
+        let mut configurations: Vec<Configuration> = Vec::new();
+        for (heuristic, minimization) in heuristics::HEURISTICS.iter().flat_map(|&heuristic| [(heuristic, false), (heuristic, true)]) {
+            configurations.push(Configuration {
+                engine: Engine::Cdcl,
+                workers: 1,
+                heuristic,
+                reduction: reduction::DEFAULT_POLICY,
+                preprocessing: false,
+                variable_elimination: false,
+                probing: false,
+                blocked_clause_elimination: false,
+                covered_clause_elimination: false,
+                minimization,
+            });
+            for techniques in 0..16 {
+                configurations.push(Configuration {
+                    engine: Engine::Cdcl,
+                    workers: 1,
+                    heuristic,
+                    reduction: reduction::DEFAULT_POLICY,
+                    preprocessing: true,
+                    variable_elimination: techniques & 1 != 0,
+                    probing: techniques & 2 != 0,
+                    blocked_clause_elimination: techniques & 4 != 0,
+                    covered_clause_elimination: techniques & 8 != 0,
+                    minimization,
+                });
+            }
+        }
+        for (heuristic, policy) in heuristics::HEURISTICS.iter().flat_map(|&heuristic| reduction::POLICIES.iter().map(move |&policy| (heuristic, policy))) {
+            if policy != reduction::DEFAULT_POLICY {
+                configurations.push(Configuration {
+                    engine: Engine::Cdcl,
+                    workers: 1,
+                    heuristic,
+                    reduction: policy,
+                    preprocessing: false,
+                    variable_elimination: false,
+                    probing: false,
+                    blocked_clause_elimination: false,
+                    covered_clause_elimination: false,
+                    minimization: true,
+                });
+            }
+        }
+        configurations.push(Configuration {
+            engine: Engine::Cdcl,
+            workers: PORTFOLIO_WORKERS,
+            heuristic: heuristics::DEFAULT_HEURISTIC,
+            reduction: reduction::DEFAULT_POLICY,
+            preprocessing: false,
+            variable_elimination: false,
+            probing: false,
+            blocked_clause_elimination: false,
+            covered_clause_elimination: false,
+            minimization: true,
+        });
+        let engines = heuristics::HEURISTICS.iter().map(|&heuristic| (Engine::Dpll, heuristic))
+            .chain([Engine::DavisPutnam, Engine::CubeAndConquer, Engine::WalkSat, Engine::ProbSat].map(|engine| (engine, heuristics::DEFAULT_HEURISTIC)));
+        for (engine, heuristic) in engines {
+            configurations.push(Configuration {
+                engine,
+                workers: 1,
+                heuristic,
+                reduction: reduction::DEFAULT_POLICY,
+                preprocessing: false,
+                variable_elimination: false,
+                probing: false,
+                blocked_clause_elimination: false,
+                covered_clause_elimination: false,
+                minimization: false,
+            });
+        }
+        return configurations;
+    }
+
+    /// Check if the engine of the configuration is a local search, it cannot prove that a formula is unsatisfiable
+    /// 
+    /// # Returns
+    /// 
+    /// * `bool` - True if the engine can return unknown without a limit
+    /// 
+    fn is_incomplete(&self) -> bool {
+        return matches!(self.engine, Engine::WalkSat | Engine::ProbSat);
+    }
+
+    /// Creates a solver with the configuration
+    /// 
+    /// # Returns
+    /// 
+    /// * `Solver` - The solver, without a formula
+    /// 
+    fn solver(&self) -> Solver {
+        let mut solver = Solver::new();
+        solver.set_engine(self.engine);
+        solver.set_portfolio_workers(self.workers);
+        if self.is_incomplete() {
+            solver.set_local_search_max_flips(MAX_FLIPS);
+        }
+        let _ = solver.set_heuristic(self.heuristic);
+        let _ = solver.set_reduction_policy(self.reduction);
+        if self.reduction != reduction::DEFAULT_POLICY {
+            solver.set_reduction_first_limit(Some(0));
+            solver.set_reduction_growth(1.0);
+        }
+        solver.set_preprocessing_enabled(self.preprocessing);
+        solver.set_variable_elimination_enabled(self.variable_elimination);
+        solver.set_probing_enabled(self.probing);
+        solver.set_blocked_clause_elimination_enabled(self.blocked_clause_elimination);
+        solver.set_covered_clause_elimination_enabled(self.covered_clause_elimination);
+        solver.set_minimization_enabled(self.minimization);
+        return solver;
+    }
+}
+
+impl fmt::Display for Configuration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let engine = format!("-engine {}", self.engine.to_string().to_lowercase());
+        let flips = format!("-flips {}", MAX_FLIPS);
+        let workers = format!("-portfolio {}", self.workers);
+        let heuristic = format!("-heuristic {}", self.heuristic);
+        let reduction = format!("-reduce \"{}\" -reduce-first 0 -reduce-growth 1", self.reduction);
+        let options: Vec<&str> = [
+            (self.engine != Engine::Cdcl, engine.as_str()),
+            (self.is_incomplete(), flips.as_str()),
+            (self.workers > 1, workers.as_str()),
+            (self.engine == Engine::Cdcl || self.engine == Engine::Dpll, heuristic.as_str()),
+            (self.reduction != reduction::DEFAULT_POLICY, reduction.as_str()),
+            (self.preprocessing, "-preprocess"),
+            (self.variable_elimination, "-bve"),
+            (self.probing, "-probe"),
+            (self.blocked_clause_elimination, "-bce"),
+            (self.covered_clause_elimination, "-cce"),
+            (self.minimization, "-minimize"),
+        ].iter().filter(|(enabled, _)| *enabled).map(|(_, option)| *option).collect();
+
+        write!(f, "{}", options.join(" "))
+    }
+}
+
+/// Runs the differential fuzzing of the solver
+/// Every iteration generates a small random formula and solves it with every configuration of the solver, the results are
+/// compared with a truth table and the models and the backbones are verified. When a configuration disagrees, the formula is delta-minimized
+/// keeping the failure, and it is saved in the output directory.
+/// Every iteration also generates a random pseudo-Boolean constraint and checks its clauses with every encoding, a failing
+/// constraint is saved in the output directory as an OPB file.
+/// 
+/// # Arguments
+/// 
+/// * `iterations` - The number of random formulas
+/// * `seed` - The seed of the random generator
+/// * `output_directory` - The path of the directory of the failing formulas
+/// 
+/// # Returns
+/// 
+/// * `Result<usize, std::io::Error>` - The number of failing formulas
+/// 
+pub fn run(iterations: usize, seed: u64, output_directory: &str) -> Result<usize, std::io::Error> {
+
+    let configurations = Configuration::all();
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut failures = 0;
+
+    // The panics of the solver are failures, they are reported with the formula instead of the default message.
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut iteration = 0;
+    while iteration < iterations && !interrupt::is_interrupted() {
+        iteration += 1;
+        let instance = random_instance(&mut rng);
+
+        for configuration in configurations.iter() {
+            if let Some(failure) = check(&instance, configuration) {
+                failures += 1;
+                let minimized = minimize(instance.clone(), configuration);
+                let failure = check(&minimized, configuration).unwrap_or(failure);
+
+                files::create_directory(output_directory)?;
+                let path = format!("{}/fuzz-{}-{}.cnf", output_directory, seed, iteration);
+                let contents = format!(
+                    "c fuzz failure with options: {}\nc {}\n{}",
+                    configuration, failure, to_formula(&minimized).to_dimacs()
+                );
+                files::write_file(&path, &contents)?;
+                println!(
+                    "Iteration {}: {} with options '{}', minimized from {} to {} constraints and saved to {}",
+                    iteration, failure, configuration, instance.len(), minimized.len(), path
+                );
+                break;
+            }
+        }
+
+        let constraint = random_pseudo_boolean(&mut rng);
+        for encoding in Encoding::ALL {
+            if let Some(failure) = check_encoding(&constraint, encoding) {
+                failures += 1;
+                files::create_directory(output_directory)?;
+                let path = format!("{}/fuzz-{}-{}.opb", output_directory, seed, iteration);
+                let contents = format!("* fuzz failure of the {} encoding\n* {}\n{}\n", encoding, failure, constraint);
+                files::write_file(&path, &contents)?;
+                println!("Iteration {}: {} with the {} encoding of {}, saved to {}", iteration, failure, encoding, constraint, path);
+                break;
+            }
+        }
+
+        if iteration % 100 == 0 {
+            println!("{} formulas checked, {} failures", iteration, failures);
+        }
+    }
+
+    panic::set_hook(default_hook);
+
+    println!("");
+    println!("Formulas: {}", iteration);
+    println!("Configurations: {}", configurations.len());
+    println!("Failures: {}", failures);
+    if interrupt::is_interrupted() {
+        println!("Fuzzing interrupted by the user.");
+    }
+
+    return Ok(failures);
+}
+
+/// Generates a small random formula
+/// The clauses have between 1 and 4 distinct variables, so unit and binary clauses are frequent.
+/// A quarter of the formulas are 2-CNF, Horn or dual-Horn, so the linear-time solvers are checked too.
+/// Some formulas have XOR constraints encoded as clauses, so the Gauss-Jordan elimination is checked too.
+/// Some formulas have native XOR constraints, a variable can appear twice in them, so the empty constraints are checked too.
+/// 
+/// # Arguments
+/// 
+/// * `rng` - The random generator
+/// 
+/// # Returns
+/// 
+/// * `Instance` - The formula
+/// 
+fn random_instance(rng: &mut StdRng) -> Instance {
+    let num_variables = rng.gen_range(3..=MAX_VARIABLES);
+    let num_clauses = rng.gen_range(1..=5 * num_variables);
+    let fragment = rng.gen_range(0..12);
+    let mut clauses: Vec<Vec<isize>> = Vec::new();
+    for _ in 0..num_clauses {
+        let max_length = if fragment == 0 { 2 } else { 4 };
+        let length = rng.gen_range(1..=max_length.min(num_variables));
+        let mut clause: Vec<isize> = sample(rng, num_variables, length).into_iter()
+            .map(|variable| (variable + 1) as isize)
+            .map(|variable| if rng.gen_bool(0.5) { -variable } else { variable })
+            .collect();
+        // Only the first literal keeps its sign in a Horn or dual-Horn clause.
+        match fragment {
+            1 => clause.iter_mut().skip(1).for_each(|literal| *literal = -literal.abs()),
+            2 => clause.iter_mut().skip(1).for_each(|literal| *literal = literal.abs()),
+            _ => (),
+        }
+        clauses.push(clause);
+    }
+    if fragment == 3 {
+        for _ in 0..rng.gen_range(1..=3) {
+            let length = rng.gen_range(3..=4.min(num_variables));
+            let mut xor = Xor::new();
+            xor.load_vec(sample(rng, num_variables, length).into_iter()
+                .map(|variable| (variable + 1) as isize)
+                .map(|variable| if rng.gen_bool(0.5) { -variable } else { variable })
+                .collect());
+            clauses.extend(xor.to_clauses());
+        }
+    }
+    let mut xors: Vec<Vec<isize>> = Vec::new();
+    if fragment == 4 {
+        for _ in 0..rng.gen_range(1..=3) {
+            let length = rng.gen_range(1..=4.min(num_variables));
+            let mut xor: Vec<isize> = sample(rng, num_variables, length).into_iter()
+                .map(|variable| (variable + 1) as isize)
+                .map(|variable| if rng.gen_bool(0.5) { -variable } else { variable })
+                .collect();
+            // A repeated variable cancels out, "x1 -1 0" is an empty constraint that is always true.
+            if rng.gen_bool(0.25) {
+                let literal = xor[rng.gen_range(0..xor.len())];
+                xor.push(if rng.gen_bool(0.5) { -literal } else { literal });
+            }
+            xors.push(xor);
+        }
+    }
+    return Instance { clauses, xors };
+}
+
+/// Builds a formula from its clauses and its XOR constraints
+/// 
+/// # Arguments
+/// 
+/// * `instance` - The random formula
+/// 
+/// # Returns
+/// 
+/// * `Formula` - The formula
+/// 
+fn to_formula(instance: &Instance) -> Formula {
+    let mut formula = Formula::new();
+    for clause in instance.clauses.iter() {
+        formula.add_clause(clause.clone());
+    }
+    for xor in instance.xors.iter() {
+        formula.add_xor(xor.clone());
+    }
+    return formula;
+}
+
+/// Decides a formula by enumerating every assignment
+/// 
+/// # Arguments
+/// 
+/// * `instance` - The formula
+/// 
+/// # Returns
+/// 
+/// * `SAT` - Satisfiable if an assignment satisfies every constraint, unsatisfiable otherwise
+/// 
+fn truth_table(instance: &Instance) -> SAT {
+    for assignment in 0..(1usize << instance.num_variables()) {
+        if instance.is_satisfied_by(assignment) {
+            return SAT::Satisfiable;
+        }
+    }
+    return SAT::Unsatisfiable;
+}
+
+/// Computes the backbone of a satisfiable formula by enumerating every assignment
+/// 
+/// # Arguments
+/// 
+/// * `instance` - The formula
+/// 
+/// # Returns
+/// 
+/// * `Vec<isize>` - The literals that are true in every model, sorted by variable
+/// 
+fn truth_table_backbone(instance: &Instance) -> Vec<isize> {
+    let num_variables = instance.num_variables();
+    // The values of the variables that are true and false in some model.
+    let (mut true_in_some, mut false_in_some) = (0usize, 0usize);
+    for assignment in 0..(1usize << num_variables) {
+        if instance.is_satisfied_by(assignment) {
+            true_in_some |= assignment;
+            false_in_some |= !assignment;
+        }
+    }
+    return (1..=num_variables)
+        .filter_map(|variable| match (true_in_some >> (variable - 1) & 1, false_in_some >> (variable - 1) & 1) {
+            (1, 0) => Some(variable as isize),
+            (0, 1) => Some(-(variable as isize)),
+            _ => None,
+        })
+        .collect();
+}
+
+/// Solves a formula with a configuration and compares the result with the truth table
+/// A satisfiable formula is solved again to compute its backbone, so the incremental calls with assumptions are checked too.
+/// 
+/// # Arguments
+/// 
+/// * `instance` - The formula
+/// * `configuration` - The configuration of the solver
+/// 
+/// # Returns
+/// 
+/// * `Option<String>` - The description of the failure, None if the solver is correct
+/// 
+fn check(instance: &Instance, configuration: &Configuration) -> Option<String> {
+    let expected = truth_table(instance);
+
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut solver = configuration.solver();
+        solver.formula = to_formula(instance);
+        let result = solver.solve();
+        let verified = result != Ok(SAT::Satisfiable) || solver.verify_model();
+        let backbone = match result {
+            Ok(SAT::Satisfiable) if !configuration.is_incomplete() => {
+                let mut solver = configuration.solver();
+                solver.formula = to_formula(instance);
+                Some(solver.compute_backbone())
+            },
+            _ => None,
+        };
+        (result, verified, backbone)
+    }));
+
+    // An interrupted search is unknown, it is not a failure of the solver.
+    if interrupt::is_interrupted() {
+        return None;
+    }
+
+    return match outcome {
+        Err(payload) => {
+            let message = match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+                (Some(message), _) => message.to_string(),
+                (_, Some(message)) => message.clone(),
+                _ => "unknown panic".to_string(),
+            };
+            Some(format!("panic: {}", message))
+        },
+        Ok((Err(()), _, _)) => Some("the solver returned an error".to_string()),
+        Ok((Ok(SAT::Unknown), _, _)) if configuration.is_incomplete() => None,
+        Ok((Ok(sat), _, _)) if sat != expected => Some(format!("expected {} but the solver returned {}", expected, sat)),
+        Ok((Ok(SAT::Satisfiable), false, _)) => Some("the model does not satisfy the formula".to_string()),
+        Ok((Ok(SAT::Satisfiable), true, Some(backbone))) => {
+            let expected_backbone = truth_table_backbone(instance);
+            match backbone {
+                Ok((SAT::Satisfiable, backbone)) if backbone == expected_backbone => None,
+                Ok((SAT::Satisfiable, backbone)) => Some(format!("expected the backbone {:?} but the solver returned {:?}", expected_backbone, backbone)),
+                Ok((sat, _)) => Some(format!("the backbone computation returned {}", sat)),
+                Err(()) => Some("the backbone computation returned an error".to_string()),
+            }
+        },
+        Ok(_) => None,
+    };
+}
+
+/// Generates a small random pseudo-Boolean constraint
+/// Half of the constraints are cardinality constraints, so every encoding is checked. The weights can be negative and the literals
+/// can be negated, and the bound goes from below the lowest sum to above the highest one, so the trivial constraints are checked too.
+/// 
+/// # Arguments
+/// 
+/// * `rng` - The random generator
+/// 
+/// # Returns
+/// 
+/// * `PseudoBoolean` - The constraint
+/// 
+fn random_pseudo_boolean(rng: &mut StdRng) -> PseudoBoolean {
+    let num_variables = rng.gen_range(1..=MAX_CONSTRAINT_VARIABLES);
+    let max_weight = if rng.gen_bool(0.5) { 1 } else { MAX_WEIGHT };
+    let terms: Vec<(isize, isize)> = (1..=num_variables as isize)
+        .map(|variable| {
+            let weight = rng.gen_range(1..=max_weight);
+            let weight = if rng.gen_bool(0.3) { -weight } else { weight };
+            (weight, if rng.gen_bool(0.5) { -variable } else { variable })
+        })
+        .collect();
+    let comparator = [Comparator::AtMost, Comparator::AtLeast, Comparator::Exactly][rng.gen_range(0..3)];
+    let lowest: isize = terms.iter().map(|&(weight, _)| weight.min(0)).sum();
+    let highest: isize = terms.iter().map(|&(weight, _)| weight.max(0)).sum();
+    let bound = rng.gen_range(lowest - 1..=highest + 1);
+    return PseudoBoolean { terms, comparator, bound };
+}
+
+/// Checks the clauses of a pseudo-Boolean constraint with an encoding
+/// The projection of the models of the clauses onto the variables of the constraint must be the set of assignments that satisfy
+/// the constraint: every assignment of the variables is checked, and the assignments of the auxiliary variables are searched exhaustively.
+/// An encoding that only handles cardinality constraints can refuse a constraint with different weights.
+/// 
+/// # Arguments
+/// 
+/// * `constraint` - The constraint
+/// * `encoding` - The encoding
+/// 
+/// # Returns
+/// 
+/// * `Option<String>` - The description of the failure, None if the encoding is correct
+/// 
+fn check_encoding(constraint: &PseudoBoolean, encoding: Encoding) -> Option<String> {
+    let num_variables = constraint.terms.len();
+
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut formula = Formula::new();
+        formula.reserve_variables(num_variables);
+        let result = formula.add_pseudo_boolean(&constraint.terms, constraint.comparator, constraint.bound, encoding);
+        (result, formula)
+    }));
+
+    let formula = match outcome {
+        Err(_) => return Some("the encoding panicked".to_string()),
+        Ok((Err(()), _)) => {
+            let is_cardinality = constraint.terms.iter().all(|&(weight, _)| weight.abs() == constraint.terms[0].0.abs());
+            if encoding.is_pseudo_boolean() || is_cardinality {
+                return Some("the encoding refused the constraint".to_string());
+            }
+            return None;
+        },
+        Ok((Ok(()), formula)) => formula,
+    };
+
+    let clauses: Vec<Vec<isize>> = formula.get_clauses().iter()
+        .map(|clause| clause.iter_literals().cloned().collect())
+        .collect();
+    let mut values: Vec<Option<bool>> = vec![None; formula.get_num_variables() + 1];
+    for assignment in 0..(1usize << num_variables) {
+        for variable in 1..=num_variables {
+            values[variable] = Some(assignment >> (variable - 1) & 1 == 1);
+        }
+        let expected = constraint.is_satisfied_by(assignment);
+        if has_extension(&clauses, &mut values) != expected {
+            let literals: Vec<String> = (1..=num_variables)
+                .map(|variable| if assignment >> (variable - 1) & 1 == 1 { variable.to_string() } else { format!("-{}", variable) })
+                .collect();
+            return Some(format!(
+                "the assignment {} {} the constraint but {} the clauses",
+                literals.join(" "),
+                if expected { "satisfies" } else { "falsifies" },
+                if expected { "falsifies" } else { "satisfies" }
+            ));
+        }
+    }
+    return None;
+}
+
+/// Check if a partial assignment can be extended to an assignment that satisfies every clause
+/// The unit clauses are propagated, then both values of the first unassigned variable are tried, so every extension is enumerated
+/// unless a clause is falsified.
+/// 
+/// # Arguments
+/// 
+/// * `clauses` - The clauses
+/// * `values` - The values of the variables, indexed by variable
+/// 
+/// # Returns
+/// 
+/// * `bool` - True if an extension satisfies every clause, the values of the unassigned variables are restored
+/// 
+fn has_extension(clauses: &Vec<Vec<isize>>, values: &mut Vec<Option<bool>>) -> bool {
+    let mut propagated: Vec<usize> = Vec::new();
+    let extended = loop {
+        let mut falsified = false;
+        let mut unit: Option<isize> = None;
+        for clause in clauses.iter() {
+            if clause.iter().any(|&literal| values[literal.unsigned_abs()] == Some(literal > 0)) {
+                continue;
+            }
+            let mut unassigned = clause.iter().filter(|&&literal| values[literal.unsigned_abs()].is_none());
+            match (unassigned.next(), unassigned.next()) {
+                (None, _) => falsified = true,
+                (Some(&literal), None) => unit = Some(literal),
+                _ => continue,
+            }
+            break;
+        }
+        if falsified {
+            break false;
+        }
+        if let Some(literal) = unit {
+            values[literal.unsigned_abs()] = Some(literal > 0);
+            propagated.push(literal.unsigned_abs());
+            continue;
+        }
+        let variable = match (1..values.len()).find(|&variable| values[variable].is_none()) {
+            Some(variable) => variable,
+            None => break true,
+        };
+        let mut extended = false;
+        for value in [false, true] {
+            values[variable] = Some(value);
+            if has_extension(clauses, values) {
+                extended = true;
+                break;
+            }
+        }
+        values[variable] = None;
+        break extended;
+    };
+    propagated.iter().for_each(|&variable| values[variable] = None);
+    return extended;
+}
+
+/// Delta-minimizes a failing formula
+/// Chunks of clauses are removed while the configuration still fails, then the XOR constraints, then single literals of the clauses.
+/// 
+/// # Arguments
+/// 
+/// * `instance` - The failing formula
+/// * `configuration` - The configuration of the solver that fails
+/// 
+/// # Returns
+/// 
+/// * `Instance` - A smaller formula where the configuration fails
+/// 
+fn minimize(mut instance: Instance, configuration: &Configuration) -> Instance {
+
+    let mut chunk = instance.clauses.len() / 2;
+    while chunk >= 1 {
+        let mut start = 0;
+        while start < instance.clauses.len() && instance.len() > 1 {
+            let end = (start + chunk).min(instance.clauses.len());
+            let mut candidate = instance.clone();
+            candidate.clauses.drain(start..end);
+            if candidate.len() != 0 && check(&candidate, configuration).is_some() {
+                instance = candidate;
+            } else {
+                start = end;
+            }
+        }
+        chunk /= 2;
+    }
+
+    let mut xor_idx = 0;
+    while xor_idx < instance.xors.len() && instance.len() > 1 {
+        let mut candidate = instance.clone();
+        candidate.xors.remove(xor_idx);
+        if check(&candidate, configuration).is_some() {
+            instance = candidate;
+        } else {
+            xor_idx += 1;
+        }
+    }
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for clause_idx in 0..instance.clauses.len() {
+            let mut literal_idx = 0;
+            while literal_idx < instance.clauses[clause_idx].len() && instance.clauses[clause_idx].len() > 1 {
+                let mut candidate = instance.clone();
+                candidate.clauses[clause_idx].remove(literal_idx);
+                if check(&candidate, configuration).is_some() {
+                    instance = candidate;
+                    changed = true;
+                } else {
+                    literal_idx += 1;
+                }
+            }
+        }
+    }
+
+    return instance;
+}
@@ -0,0 +1,212 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::{rngs::StdRng, seq::index::sample, Rng, SeedableRng};
+
+use crate::classes::formula::Formula;
+use crate::consts::encoding::Encoding;
+
+pub const GENERATORS: [&str; 4] = ["ksat", "php", "parity", "coloring"];
+
+/// Generates a formula from its kind and its parameters
+/// 
+/// * `ksat` - Uniform random k-SAT: `n` variables, `m` clauses or `ratio` clauses per variable (default 4.26), `k` literals per clause (default 3)
+/// * `php` - Pigeonhole principle PHP(n+1, n): `n` holes
+/// * `parity` - Random XOR constraints: `n` variables, `m` constraints (default n), `k` variables per constraint (default 3, at most 10)
+/// * `coloring` - Random graph coloring: `n` vertices, `m` edges (default 2n), `k` colors (default 3)
+/// 
+/// The random generators use the `seed` parameter (default 0).
+/// 
+/// # Arguments
+/// 
+/// * `kind` - The kind of formula
+/// * `parameters` - The parameters, by name
+/// 
+/// # Returns
+/// 
+/// * `Result<Formula, ()>` - The formula, an error if the kind is unknown or a parameter is missing or not valid
+/// 
+pub fn generate(kind: &str, parameters: &HashMap<String, String>) -> Result<Formula, ()> {
+    let integer = |name: &str, default: Option<usize>| -> Result<usize, ()> {
+        match parameters.get(name) {
+            Some(value) => value.parse::<usize>().map_err(|_| ()),
+            None => default.ok_or(()),
+        }
+    };
+    let seed = integer("seed", Some(0))? as u64;
+
+    match kind {
+        "ksat" => {
+            let num_variables = integer("n", None)?;
+            let num_clauses = match (parameters.get("m"), parameters.get("ratio")) {
+                (Some(_), _) => integer("m", None)?,
+                (None, Some(ratio)) => (ratio.parse::<f64>().map_err(|_| ())? * num_variables as f64).round() as usize,
+                (None, None) => (4.26 * num_variables as f64).round() as usize,
+            };
+            random_k_sat(integer("k", Some(3))?, num_variables, num_clauses, seed)
+        },
+        "php" => pigeonhole(integer("n", None)?),
+        "parity" => {
+            let num_variables = integer("n", None)?;
+            parity(num_variables, integer("m", Some(num_variables))?, integer("k", Some(3))?, seed)
+        },
+        "coloring" => {
+            let num_vertices = integer("n", None)?;
+            graph_coloring(num_vertices, integer("m", Some(2 * num_vertices))?, integer("k", Some(3))?, seed)
+        },
+        _ => Err(()),
+    }
+}
+
+/// Generates a uniform random k-SAT formula
+/// Every clause has k distinct variables, each negated with probability 1/2.
+/// 
+/// # Arguments
+/// 
+/// * `k` - The number of literals of each clause
+/// * `num_variables` - The number of variables
+/// * `num_clauses` - The number of clauses
+/// * `seed` - The seed of the random generator
+/// 
+/// # Returns
+/// 
+/// * `Result<Formula, ()>` - The formula, an error if k is 0 or greater than the number of variables
+/// 
+pub fn random_k_sat(k: usize, num_variables: usize, num_clauses: usize, seed: u64) -> Result<Formula, ()> {
+    if k == 0 || k > num_variables {
+        return Err(());
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut formula = Formula::new();
+    for _ in 0..num_clauses {
+        let literals: Vec<isize> = sample(&mut rng, num_variables, k).into_iter()
+            .map(|variable| (variable + 1) as isize)
+            .map(|variable| if rng.gen_bool(0.5) { -variable } else { variable })
+            .collect();
+        formula.add_clause(literals);
+    }
+    formula.reserve_variables(num_variables);
+    return Ok(formula);
+}
+
+/// Generates the pigeonhole formula PHP(n+1, n), which is unsatisfiable
+/// The variable of the pigeon p in the hole h is p * n + h + 1.
+/// 
+/// # Arguments
+/// 
+/// * `holes` - The number of holes
+/// 
+/// # Returns
+/// 
+/// * `Result<Formula, ()>` - The formula, an error if there are no holes
+/// 
+pub fn pigeonhole(holes: usize) -> Result<Formula, ()> {
+    if holes == 0 {
+        return Err(());
+    }
+
+    let variable = |pigeon: usize, hole: usize| (pigeon * holes + hole + 1) as isize;
+    let mut formula = Formula::new();
+
+    // Every pigeon is in a hole.
+    for pigeon in 0..=holes {
+        formula.add_clause((0..holes).map(|hole| variable(pigeon, hole)).collect());
+    }
+    // No two pigeons are in the same hole.
+    for hole in 0..holes {
+        let pigeons: Vec<isize> = (0..=holes).map(|pigeon| variable(pigeon, hole)).collect();
+        formula.add_at_most(&pigeons, 1, Encoding::Pairwise)?;
+    }
+    return Ok(formula);
+}
+
+/// Generates random XOR constraints
+/// Every constraint has k distinct variables and a random parity, it is encoded by the 2^(k-1) clauses that forbid the assignments with the wrong parity.
+/// 
+/// # Arguments
+/// 
+/// * `num_variables` - The number of variables
+/// * `num_constraints` - The number of XOR constraints
+/// * `k` - The number of variables of each constraint
+/// * `seed` - The seed of the random generator
+/// 
+/// # Returns
+/// 
+/// * `Result<Formula, ()>` - The formula, an error if k is 0, greater than 10 or greater than the number of variables
+/// 
+pub fn parity(num_variables: usize, num_constraints: usize, k: usize, seed: u64) -> Result<Formula, ()> {
+    if k == 0 || k > 10 || k > num_variables {
+        return Err(());
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut formula = Formula::new();
+    for _ in 0..num_constraints {
+        let variables: Vec<isize> = sample(&mut rng, num_variables, k).into_iter().map(|variable| (variable + 1) as isize).collect();
+        let parity = rng.gen_bool(0.5);
+        for assignment in 0..(1usize << k) {
+            // The clause is falsified only by the assignment, so it is added if the assignment has the wrong parity.
+            if (assignment.count_ones() % 2 == 1) != parity {
+                formula.add_clause(variables.iter().enumerate().map(|(idx, &variable)| {
+                    if assignment & (1 << idx) != 0 { -variable } else { variable }
+                }).collect());
+            }
+        }
+    }
+    formula.reserve_variables(num_variables);
+    return Ok(formula);
+}
+
+/// Generates the coloring of a random graph
+/// The variable of the vertex v with the color c is v * k + c + 1.
+/// 
+/// # Arguments
+/// 
+/// * `num_vertices` - The number of vertices
+/// * `num_edges` - The number of distinct edges, chosen uniformly
+/// * `colors` - The number of colors
+/// * `seed` - The seed of the random generator
+/// 
+/// # Returns
+/// 
+/// * `Result<Formula, ()>` - The formula, an error if there are no vertices, no colors or too many edges
+/// 
+pub fn graph_coloring(num_vertices: usize, num_edges: usize, colors: usize, seed: u64) -> Result<Formula, ()> {
+    if num_vertices == 0 || colors == 0 || num_edges > num_vertices * num_vertices.saturating_sub(1) / 2 {
+        return Err(());
+    }
+
+    let variable = |vertex: usize, color: usize| (vertex * colors + color + 1) as isize;
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut formula = Formula::new();
+
+    let mut edges: HashSet<(usize, usize)> = HashSet::new();
+    while edges.len() < num_edges {
+        let first = rng.gen_range(0..num_vertices);
+        let second = rng.gen_range(0..num_vertices);
+        if first != second {
+            edges.insert((first.min(second), first.max(second)));
+        }
+    }
+    let mut edges: Vec<(usize, usize)> = edges.into_iter().collect();
+    edges.sort();
+
+    for vertex in 0..num_vertices {
+        // Every vertex has a color.
+        formula.add_clause((0..colors).map(|color| variable(vertex, color)).collect());
+        // Every vertex has at most one color.
+        for first in 0..colors {
+            for second in (first + 1)..colors {
+                formula.add_clause(vec![-variable(vertex, first), -variable(vertex, second)]);
+            }
+        }
+    }
+    // Adjacent vertices have different colors.
+    for (first, second) in edges {
+        for color in 0..colors {
+            formula.add_clause(vec![-variable(first, color), -variable(second, color)]);
+        }
+    }
+    formula.reserve_variables(num_vertices * colors);
+    return Ok(formula);
+}
\ No newline at end of file